@@ -0,0 +1,150 @@
+use pinocchio::{
+    account_info::AccountInfo, ProgramResult, program_error::ProgramError,
+    sysvars::{clock::Clock, Sysvar},
+};
+
+use pinocchio_log::log;
+
+use crate::state::{ProposalState, ProposalStatus};
+
+/// Lets the original proposer withdraw a still-`Active` proposal before it
+/// expires, marking it `Cancelled` and reclaiming the account's rent lamports
+/// back to themself, rather than leaving it to sit until `process_vote`
+/// eventually marks it `Expired`.
+pub fn close_proposal(
+    proposal: &AccountInfo,
+    proposer: &AccountInfo,
+) -> ProgramResult {
+
+    if !proposer.is_signer() {
+        log!("Proposer must sign to close a proposal");
+        return Err(ProgramError::MissingRequiredSignature);
+    }
+
+    let proposal_account = ProposalState::from_account_info(proposal)?;
+
+    if !matches!(proposal_account.result, ProposalStatus::Active) {
+        log!("Proposal is not active; cannot close it");
+        return Err(ProgramError::InvalidAccountData);
+    }
+
+    let clock = Clock::get()?;
+    if clock.unix_timestamp as u64 > proposal_account.expiry {
+        log!("Proposal has expired; cannot close it");
+        return Err(ProgramError::InvalidAccountData);
+    }
+
+    if proposal_account.proposer != *proposer.key() {
+        log!("Only the original proposer may close this proposal");
+        return Err(ProgramError::InvalidAccountData);
+    }
+
+    proposal_account.result = ProposalStatus::Cancelled;
+
+    let proposal_lamports = proposal.lamports();
+    *proposer.try_borrow_mut_lamports()? += proposal_lamports;
+    *proposal.try_borrow_mut_lamports()? = 0;
+    proposal.realloc(0, false)?;
+
+    log!("Proposal cancelled and rent reclaimed by proposer");
+
+    Ok(())
+}
+
+// -------------------------- TESTING close_proposal -----------------------------
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::instructions::layout::MultisigInstructions;
+    use crate::state::{GovernanceAction, ProposalAction, VoteChange, MAX_VOTE_CHANGES};
+    use mollusk_svm::{Mollusk, result::Check};
+    use solana_sdk::{
+        account::Account,
+        instruction::{AccountMeta, Instruction},
+        pubkey::Pubkey,
+        pubkey,
+    };
+
+    const PROGRAM_ID: Pubkey = pubkey!("3X4xfxBGSWDc24HhACGxk5VdDAJzg9mxtUvvHvwjQcec");
+
+    #[test]
+    fn test_close_proposal() {
+        let mollusk = Mollusk::new(&PROGRAM_ID, "target/deploy/multisig_pinocchio");
+
+        let multisig_pda = Pubkey::new_from_array([1u8; 32]);
+        let proposal_id = 0u64;
+        let (proposal_pda, _) = Pubkey::find_program_address(
+            &[b"proposal", multisig_pda.as_ref(), &proposal_id.to_le_bytes()],
+            &PROGRAM_ID
+        );
+
+        let proposer = Pubkey::new_from_array([10u8; 32]);
+
+        let mut active_members = [[0u8; 32]; 10];
+        active_members[0] = proposer.to_bytes();
+
+        let proposal = ProposalState {
+            version: ProposalState::CURRENT_VERSION,
+            proposal_id,
+            expiry: 2_000_000_000,
+            result: ProposalStatus::Active,
+            bump: 255,
+            active_members,
+            votes: [0u8; 10],
+            created_time: 1_900_000_000,
+            vote_changes: [VoteChange::EMPTY; MAX_VOTE_CHANGES],
+            change_head: 0,
+            change_len: 0,
+            action: ProposalAction::EMPTY,
+            yes_weight: 0,
+            no_weight: 0,
+            governance_action: GovernanceAction::NONE,
+            multisig: multisig_pda.to_bytes(),
+            proposer: proposer.to_bytes(),
+        };
+
+        let mut proposal_data = vec![0u8; ProposalState::LEN];
+        unsafe {
+            std::ptr::copy_nonoverlapping(
+                &proposal as *const ProposalState as *const u8,
+                proposal_data.as_mut_ptr(),
+                ProposalState::LEN,
+            );
+        }
+
+        let proposal_account = Account {
+            lamports: solana_sdk::rent::Rent::default().minimum_balance(ProposalState::LEN),
+            data: proposal_data,
+            owner: PROGRAM_ID,
+            executable: false,
+            rent_epoch: 0,
+        };
+
+        let proposer_account = Account {
+            lamports: 1_000_000,
+            data: vec![],
+            owner: solana_sdk::system_program::id(),
+            executable: false,
+            rent_epoch: 0,
+        };
+
+        let instruction = Instruction {
+            program_id: PROGRAM_ID,
+            accounts: vec![
+                AccountMeta::new(proposal_pda, false),
+                AccountMeta::new(proposer, true),
+            ],
+            data: vec![MultisigInstructions::CloseProposal as u8],
+        };
+
+        mollusk.process_and_validate_instruction(
+            &instruction,
+            &vec![
+                (proposal_pda, proposal_account),
+                (proposer, proposer_account),
+            ],
+            &[Check::success()],
+        );
+    }
+}
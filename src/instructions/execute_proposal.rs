@@ -0,0 +1,398 @@
+use pinocchio::{
+    account_info::AccountInfo,
+    instruction::{AccountMeta, Instruction, Seed, Signer},
+    program_error::ProgramError,
+    ProgramResult,
+    sysvars::{clock::Clock, Sysvar},
+};
+use pinocchio::cpi::invoke_signed;
+
+use pinocchio_log::log;
+
+use crate::state::{GovernanceActionKind, Multisig, ProposalState, ProposalStatus};
+use super::add_member::add_member;
+use super::remove_member::remove_member;
+use super::set_member_weight::set_member_weight;
+use super::process_vote::VOTE_FOR;
+
+/// Executes a passed proposal's embedded CPI action on behalf of the treasury
+/// PDA. `remaining_accounts` must list, in order, the accounts referenced by
+/// `proposal.action.accounts`.
+pub fn execute_proposal(
+    multisig: &AccountInfo,
+    proposal: &AccountInfo,
+    treasury: &AccountInfo,
+    target_program: &AccountInfo,
+    remaining_accounts: &[AccountInfo],
+) -> ProgramResult {
+
+    let multisig_account = Multisig::from_account_info(multisig)?;
+    let proposal_account = ProposalState::from_account_info(proposal)?;
+
+    // A proposal only belongs to the multisig it was created under. Without
+    // this check an attacker could vote their own throwaway proposal to
+    // `Passed` (trivially, since they control every seat in their own
+    // multisig), then execute it here against a victim's real multisig and
+    // treasury: `votes_for` would be recomputed from the victim's real
+    // `member_weights` indexed against the attacker's own `proposal_account.votes`,
+    // and the CPI would be signed with the victim's real treasury PDA seeds.
+    if proposal_account.multisig != *multisig.key() {
+        log!("Proposal does not belong to this multisig");
+        return Err(ProgramError::InvalidAccountData);
+    }
+
+    // `process_vote` already transitions a proposal to `Passed` the moment its
+    // weighted yes-votes reach `threshold`, so that (not `Active`) is the state
+    // a proposal sits in once it's ready to execute.
+    if !matches!(proposal_account.result, ProposalStatus::Passed) {
+        log!("Proposal has not passed; cannot execute it");
+        return Err(ProgramError::InvalidAccountData);
+    }
+
+    let clock = Clock::get()?;
+    let current_time = clock.unix_timestamp as u64;
+    if current_time > proposal_account.expiry {
+        log!("Proposal has expired; cannot execute it");
+        // As in `process_vote`, an instruction-level `Err` would discard this
+        // mutation entirely, so persist the status transition via `Ok(())`
+        // instead and let the caller observe the failure in account state.
+        proposal_account.result = ProposalStatus::Expired;
+        return Ok(());
+    }
+
+    // Membership and weight changes go through `add_member`/`remove_member`/
+    // `set_member_weight` directly here, the only path privileged enough to call
+    // them, rather than via CPI to the treasury like the action below.
+    match proposal_account.governance_action.kind {
+        GovernanceActionKind::AddMember => {
+            add_member(multisig, proposal_account.governance_action.member)?;
+            proposal_account.result = ProposalStatus::Executed;
+            log!("Proposal executed successfully: member added");
+            return Ok(());
+        }
+        GovernanceActionKind::RemoveMember => {
+            remove_member(multisig, proposal_account.governance_action.member)?;
+            proposal_account.result = ProposalStatus::Executed;
+            log!("Proposal executed successfully: member removed");
+            return Ok(());
+        }
+        GovernanceActionKind::SetMemberWeight => {
+            set_member_weight(
+                multisig,
+                proposal_account.governance_action.member,
+                proposal_account.governance_action.weight,
+            )?;
+            proposal_account.result = ProposalStatus::Executed;
+            log!("Proposal executed successfully: member weight updated");
+            return Ok(());
+        }
+        GovernanceActionKind::None => {}
+    }
+
+    if *treasury.key() != multisig_account.treasury_wallet {
+        return Err(ProgramError::InvalidSeeds);
+    }
+
+    let action = &proposal_account.action;
+    if !action.has_action {
+        log!("Proposal has no configured action");
+        return Err(ProgramError::InvalidAccountData);
+    }
+
+    if *target_program.key() != action.program_id {
+        log!("Target program does not match the configured action");
+        return Err(ProgramError::InvalidArgument);
+    }
+
+    // Re-derive the weighted "for" tally the same way `process_vote` does, so
+    // execution can only happen once the threshold is genuinely met.
+    let mut votes_for = 0u64;
+    for i in 0..multisig_account.member_count as usize {
+        if proposal_account.votes[i] == VOTE_FOR {
+            votes_for += multisig_account.member_weights[i];
+        }
+    }
+
+    if votes_for < multisig_account.threshold {
+        log!("Proposal has not reached its voting threshold");
+        return Err(ProgramError::InvalidAccountData);
+    }
+
+    let accounts_len = action.accounts_len as usize;
+    if remaining_accounts.len() != accounts_len {
+        log!("Expected {} accounts for this action", accounts_len as u64);
+        return Err(ProgramError::NotEnoughAccountKeys);
+    }
+
+    let mut account_metas = [AccountMeta::readonly(treasury.key()); 10];
+    for i in 0..accounts_len {
+        let expected = &action.accounts[i];
+
+        if *remaining_accounts[i].key() != expected.pubkey {
+            log!("Account {} does not match the configured action", i as u64);
+            return Err(ProgramError::InvalidArgument);
+        }
+
+        // Carry through `is_signer` too, not just `is_writable`: an action that
+        // needs an account to sign the inner CPI (e.g. the treasury PDA itself,
+        // for a SOL transfer out of the treasury) would otherwise always be sent
+        // as a non-signer and get rejected by the target program.
+        account_metas[i] = AccountMeta::new(
+            remaining_accounts[i].key(),
+            expected.is_writable,
+            expected.is_signer,
+        );
+    }
+
+    let cpi_instruction = Instruction {
+        program_id: target_program.key(),
+        accounts: &account_metas[..accounts_len],
+        data: &action.data[..action.data_len as usize],
+    };
+
+    let bump = [multisig_account.treasury_bump];
+    let seeds = [
+        Seed::from(b"treasury".as_ref()),
+        Seed::from(multisig.key().as_ref()),
+        Seed::from(&bump[..]),
+    ];
+    let signer = Signer::from(&seeds);
+
+    invoke_signed(&cpi_instruction, remaining_accounts, &[signer])?;
+
+    proposal_account.result = ProposalStatus::Executed;
+    log!("Proposal executed successfully");
+
+    Ok(())
+}
+
+// -------------------------- TESTING execute_proposal -----------------------------
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::instructions::layout::MultisigInstructions;
+    use crate::state::{
+        ActionAccountMeta, CreditBucket, GovernanceAction, VoteChange, VoteDelegate,
+        MAX_ACTION_ACCOUNTS, MAX_ACTION_DATA, MAX_CREDIT_HISTORY, MAX_VOTE_CHANGES,
+    };
+    use mollusk_svm::{program, Mollusk, result::Check};
+    use solana_sdk::{
+        account::Account,
+        instruction::{AccountMeta, Instruction},
+        pubkey::Pubkey,
+        pubkey,
+        system_program,
+        sysvar,
+        clock::Clock as SdkClock,
+    };
+
+    const PROGRAM_ID: Pubkey = pubkey!("3X4xfxBGSWDc24HhACGxk5VdDAJzg9mxtUvvHvwjQcec");
+    const CREATOR: Pubkey = Pubkey::new_from_array([1u8; 32]);
+
+    /// Exercises the treasury CPI branch (not the governance-action shortcuts
+    /// covered by add_member.rs/remove_member.rs/set_member_weight.rs), with the
+    /// treasury PDA itself listed as a signer account on the action — the
+    /// "transfer SOL out of the treasury" case this payload exists for, which
+    /// only works if `is_signer` is actually carried into the CPI's accounts.
+    #[test]
+    fn test_execute_proposal_signer_cpi() {
+        let mollusk = Mollusk::new(&PROGRAM_ID, "target/deploy/multisig_pinocchio");
+
+        let (multisig_pda, _) = Pubkey::find_program_address(
+            &[b"multisig", CREATOR.as_ref()],
+            &PROGRAM_ID
+        );
+
+        let (treasury_pda, treasury_bump) = Pubkey::find_program_address(
+            &[b"treasury", multisig_pda.as_ref()],
+            &PROGRAM_ID
+        );
+
+        let proposal_id = 0u64;
+        let (proposal_pda, _) = Pubkey::find_program_address(
+            &[b"proposal", multisig_pda.as_ref(), &proposal_id.to_le_bytes()],
+            &PROGRAM_ID
+        );
+
+        let member_1 = Pubkey::new_from_array([10u8; 32]);
+        let mut member_keys = [[0u8; 32]; 10];
+        member_keys[0] = member_1.to_bytes();
+
+        let multisig = Multisig {
+            version: Multisig::CURRENT_VERSION,
+            creator: CREATOR.to_bytes(),
+            member_count: 1,
+            memeber_keys: member_keys,
+            threshold: 1,
+            proposal_expiry: 86400,
+            total_proposals: 1,
+            treasury_wallet: treasury_pda.to_bytes(),
+            config_bump: 255,
+            treasury_bump,
+            delegates: [VoteDelegate::EMPTY; 10],
+            credit_history: [CreditBucket::EMPTY; MAX_CREDIT_HISTORY],
+            credit_head: 0,
+            credit_len: 0,
+            member_weights: [1u64; 10],
+        };
+
+        let mut multisig_data = vec![0u8; Multisig::LEN];
+        unsafe {
+            std::ptr::copy_nonoverlapping(
+                &multisig as *const Multisig as *const u8,
+                multisig_data.as_mut_ptr(),
+                Multisig::LEN,
+            );
+        }
+
+        let multisig_account = Account {
+            lamports: 1_000_000,
+            data: multisig_data,
+            owner: PROGRAM_ID,
+            executable: false,
+            rent_epoch: 0,
+        };
+
+        let destination = Pubkey::new_from_array([40u8; 32]);
+        let transfer_lamports = 1_000u64;
+
+        let mut accounts = [ActionAccountMeta::EMPTY; MAX_ACTION_ACCOUNTS];
+        accounts[0] = ActionAccountMeta {
+            pubkey: treasury_pda.to_bytes(),
+            is_signer: true,
+            is_writable: true,
+        };
+        accounts[1] = ActionAccountMeta {
+            pubkey: destination.to_bytes(),
+            is_signer: false,
+            is_writable: true,
+        };
+
+        // System program `Transfer`: `discriminator(4, LE) | lamports(8, LE)`.
+        let mut data = [0u8; MAX_ACTION_DATA];
+        data[0..4].copy_from_slice(&2u32.to_le_bytes());
+        data[4..12].copy_from_slice(&transfer_lamports.to_le_bytes());
+
+        let action = ProposalAction {
+            program_id: system_program::id().to_bytes(),
+            accounts,
+            accounts_len: 2,
+            data,
+            data_len: 12,
+            has_action: true,
+        };
+
+        let current_time = 1_640_995_200u64;
+        let mut active_members = [[0u8; 32]; 10];
+        active_members[0] = member_1.to_bytes();
+
+        let proposal = ProposalState {
+            version: ProposalState::CURRENT_VERSION,
+            proposal_id,
+            expiry: current_time + 86400,
+            result: ProposalStatus::Passed,
+            bump: 255,
+            active_members,
+            votes: [1u8; 10],
+            created_time: current_time,
+            vote_changes: [VoteChange::EMPTY; MAX_VOTE_CHANGES],
+            change_head: 0,
+            change_len: 0,
+            action,
+            yes_weight: 1,
+            no_weight: 0,
+            governance_action: GovernanceAction::NONE,
+            multisig: multisig_pda.to_bytes(),
+            proposer: member_1.to_bytes(),
+        };
+
+        let mut proposal_data = vec![0u8; ProposalState::LEN];
+        unsafe {
+            std::ptr::copy_nonoverlapping(
+                &proposal as *const ProposalState as *const u8,
+                proposal_data.as_mut_ptr(),
+                ProposalState::LEN,
+            );
+        }
+
+        let proposal_account = Account {
+            lamports: 1_000_000,
+            data: proposal_data,
+            owner: PROGRAM_ID,
+            executable: false,
+            rent_epoch: 0,
+        };
+
+        let treasury_account = Account {
+            lamports: 1_000_000,
+            data: vec![],
+            owner: solana_sdk::system_program::id(),
+            executable: false,
+            rent_epoch: 0,
+        };
+
+        let destination_account = Account {
+            lamports: 0,
+            data: vec![],
+            owner: solana_sdk::system_program::id(),
+            executable: false,
+            rent_epoch: 0,
+        };
+
+        let clock = SdkClock {
+            slot: 1000,
+            epoch_start_timestamp: current_time as i64 - 3600,
+            epoch: 10,
+            leader_schedule_epoch: 10,
+            unix_timestamp: current_time as i64,
+        };
+
+        let clock_data = unsafe {
+            std::slice::from_raw_parts(
+                &clock as *const SdkClock as *const u8,
+                std::mem::size_of::<SdkClock>(),
+            ).to_vec()
+        };
+
+        let clock_account = Account {
+            lamports: 1,
+            data: clock_data,
+            owner: sysvar::id(),
+            executable: false,
+            rent_epoch: 0,
+        };
+
+        let (system_program_id, system_program_account) = program::keyed_account_for_system_program();
+
+        // Only the 2 accounts the action itself references belong in
+        // `remaining_accounts` (`accounts_len` checks this exactly); the clock
+        // sysvar is supplied via the accounts list below, not as an instruction
+        // account, since `execute_proposal` reads it through `Clock::get()`.
+        let instruction = Instruction {
+            program_id: PROGRAM_ID,
+            accounts: vec![
+                AccountMeta::new(multisig_pda, false),
+                AccountMeta::new(proposal_pda, false),
+                AccountMeta::new(treasury_pda, false),
+                AccountMeta::new_readonly(system_program_id, false),
+                AccountMeta::new(treasury_pda, false),
+                AccountMeta::new(destination, false),
+            ],
+            data: vec![MultisigInstructions::ExecuteProposal as u8],
+        };
+
+        mollusk.process_and_validate_instruction(
+            &instruction,
+            &vec![
+                (multisig_pda, multisig_account),
+                (proposal_pda, proposal_account),
+                (treasury_pda, treasury_account),
+                (system_program_id, system_program_account),
+                (destination, destination_account),
+                (sysvar::clock::id(), clock_account),
+            ],
+            &[Check::success()],
+        );
+    }
+}
@@ -9,19 +9,15 @@ use pinocchio_log::log;
 
 use pinocchio_system::instructions::CreateAccount;
 
-use crate::state::Multisig;
+use crate::instructions::layout::{InitMultisig, MultisigInstructions};
+use crate::state::{CreditBucket, Multisig, PackedState, VoteDelegate, MAX_CREDIT_HISTORY};
 
-pub fn initialize_multisig(accounts: &[AccountInfo], data: &[u8]) -> ProgramResult {
+pub fn initialize_multisig(accounts: &[AccountInfo], payload: &InitMultisig) -> ProgramResult {
 
     let [creator, multisig, treasury_wallet, _remaining @..] = accounts else {
         return Err(ProgramError::InvalidAccountData)
     };
 
-    // Check minimum data length: discriminator(1) + member_count(8) + threshold(1) = 10 bytes minimum
-    if data.len() < 10 {
-        return Err(ProgramError::InvalidInstructionData);
-    }
-
     let seed = [b"multisig", creator.key().as_ref()];
     let multisig_account_seeds = &seed[..];
     let (multisig_pda, multisig_bump) = pubkey::find_program_address(multisig_account_seeds, &crate::ID);
@@ -44,8 +40,9 @@ pub fn initialize_multisig(accounts: &[AccountInfo], data: &[u8]) -> ProgramResu
 
     if *multisig.owner() != crate::ID {
         log!("Initializing Multisig Account");
-        
-        let lamports = Rent::get()?.minimum_balance(Multisig::LEN);
+
+        let rent = Rent::get()?;
+        let lamports = rent.minimum_balance(Multisig::LEN);
 
         CreateAccount {
             from: creator,
@@ -55,30 +52,11 @@ pub fn initialize_multisig(accounts: &[AccountInfo], data: &[u8]) -> ProgramResu
             owner: &crate::ID
         }.invoke()?;
 
-        let multisig_account = Multisig::from_account_info(multisig)?;
-        
-        // Parse data safely - skip discriminator at data[0]
-        let member_count = u64::from_le_bytes([
-            data[1], data[2], data[3], data[4], 
-            data[5], data[6], data[7], data[8]
-        ]);
+        let multisig_account = Multisig::load(multisig)?;
 
-        let threshold = data[9] as u64;
-        
-        // Parse proposal_expiry - check if we have more data
-        let proposal_expiry = if data.len() >= 18 {
-            // If we have 8 more bytes, parse as u64
-            u64::from_le_bytes([
-                data[10], data[11], data[12], data[13],
-                data[14], data[15], data[16], data[17]
-            ])
-        } else if data.len() > 10 {
-            // If we have 1 more byte, parse as u8 and convert to u64
-            data[10] as u64
-        } else {
-            // Default value
-            86400 // 24 hours in seconds
-        };
+        let member_count = payload.member_count;
+        let threshold = payload.threshold as u64;
+        let proposal_expiry = payload.proposal_expiry;
 
         // Validate parameters
         if member_count == 0 || member_count > 10 {
@@ -89,6 +67,7 @@ pub fn initialize_multisig(accounts: &[AccountInfo], data: &[u8]) -> ProgramResu
             return Err(ProgramError::InvalidInstructionData);
         };
 
+        multisig_account.version = Multisig::CURRENT_VERSION;
         multisig_account.creator = *creator.key();
         multisig_account.member_count = member_count;
         multisig_account.memeber_keys = [Pubkey::default(); 10];
@@ -98,6 +77,27 @@ pub fn initialize_multisig(accounts: &[AccountInfo], data: &[u8]) -> ProgramResu
         multisig_account.treasury_wallet = treasury_pda;
         multisig_account.config_bump = multisig_bump;
         multisig_account.treasury_bump = treasury_bump;
+        multisig_account.delegates = [VoteDelegate::EMPTY; 10];
+        multisig_account.credit_history = [CreditBucket::EMPTY; MAX_CREDIT_HISTORY];
+        multisig_account.credit_head = 0;
+        multisig_account.credit_len = 0;
+        // Default every member to equal weight 1 so `threshold` (now a required
+        // weight sum) behaves exactly like the prior one-member-one-vote count.
+        multisig_account.member_weights = [1u64; 10];
+
+        // Guard against an unpassable configuration: the weight sum across active
+        // members must be able to reach `threshold`.
+        let total_weight: u64 = multisig_account.member_weights[..member_count as usize]
+            .iter()
+            .sum();
+        if total_weight < threshold {
+            return Err(ProgramError::InvalidInstructionData);
+        }
+
+        // `CreateAccount` funds the account for exactly `Self::LEN`, but assert it
+        // explicitly so a future change to the rent calculation fails loudly here
+        // rather than leaving a non-rent-exempt account on-chain.
+        multisig_account.save_rent_exempt(multisig, &rent)?;
 
         log!("Multisig initialized successfully");
     } else {
@@ -148,16 +148,21 @@ mod tests {
         let (system_program_id, system_account) = program::keyed_account_for_system_program();
         println!("System program ID: {}", system_program_id);
 
-        // Create instruction data
-        let mut instruction_data = vec![0u8; 10]; // Minimum required: discriminator + member_count + threshold
-        instruction_data[0] = 1; // discriminator
-        instruction_data[1..9].copy_from_slice(&3u64.to_le_bytes()); // member_count = 3
-        instruction_data[9] = 2; // threshold = 2
-        
+        // Create instruction data: discriminator byte followed by the shared
+        // `InitMultisig` layout, so this test builds data the same way a
+        // real client would.
+        let payload = InitMultisig {
+            member_count: 3,
+            threshold: 2,
+            proposal_expiry: 86400,
+        };
+        let mut instruction_data = vec![MultisigInstructions::InitializeMultisig as u8];
+        instruction_data.extend_from_slice(&payload.encode());
+
         println!("Instruction data created:");
         println!("Discriminator: {}", instruction_data[0]);
-        println!("Member count: {}", u64::from_le_bytes([instruction_data[1], instruction_data[2], instruction_data[3], instruction_data[4], instruction_data[5], instruction_data[6], instruction_data[7], instruction_data[8]]));
-        println!("Threshold: {}", instruction_data[9]);
+        println!("Member count: {}", payload.member_count);
+        println!("Threshold: {}", payload.threshold);
         println!("Total data length: {} bytes", instruction_data.len());
 
         // Create instruction
@@ -1,14 +1,15 @@
 use pinocchio::{
-    account_info::AccountInfo, 
-    pubkey::Pubkey, 
-    ProgramResult, 
+    account_info::AccountInfo,
+    pubkey::Pubkey,
+    ProgramResult,
     program_error::ProgramError,
-    sysvars::{clock::Clock, Sysvar}
+    sysvars::{clock::Clock, rent::Rent, Sysvar}
 };
 
 use pinocchio_log::log;
 
-use crate::state::{multisig, proposal, Multisig, ProposalState, ProposalStatus};
+use crate::instructions::layout::{InitProposal, MultisigInstructions};
+use crate::state::{multisig, proposal, GovernanceAction, Multisig, PackedState, ProposalAction, ProposalState, ProposalStatus, VoteChange, MAX_VOTE_CHANGES};
 
 pub fn init_proposal(
     multisig: &AccountInfo,
@@ -19,7 +20,7 @@ pub fn init_proposal(
 
     log!("Initializing new proposal");
 
-    let multisig_account = Multisig::from_account_info(multisig)?;
+    let multisig_account = Multisig::load(multisig)?;
     log!("Multisig loaded with {} members", multisig_account.member_count);
 
     let proposer_pubkey = *proposer.key();
@@ -46,7 +47,7 @@ pub fn init_proposal(
 
     log!("Current time: {}, Proposal will expire at: {}", current_time, expiry_time);
 
-    let proposal_account = ProposalState::from_account_info(proposal)?;
+    let proposal_account = ProposalState::load(proposal)?;
 
     let proposal_id = multisig_account.total_proposals;
     multisig_account.total_proposals += 1;
@@ -62,6 +63,7 @@ pub fn init_proposal(
     // Initialize votes array (0 = NOT_VOTED for all members)
     let votes = [0u8; 10];
 
+    proposal_account.version = ProposalState::CURRENT_VERSION;
     proposal_account.proposal_id = proposal_id;
     proposal_account.expiry = expiry_time;
     proposal_account.result = ProposalStatus::Active;
@@ -69,7 +71,21 @@ pub fn init_proposal(
     proposal_account.active_members = active_members.map(|pk| pk);
     proposal_account.votes = votes;
     proposal_account.created_time = current_time;
-    
+    proposal_account.vote_changes = [VoteChange::EMPTY; MAX_VOTE_CHANGES];
+    proposal_account.change_head = 0;
+    proposal_account.change_len = 0;
+    proposal_account.action = ProposalAction::EMPTY;
+    proposal_account.yes_weight = 0;
+    proposal_account.no_weight = 0;
+    proposal_account.governance_action = GovernanceAction::NONE;
+    proposal_account.multisig = *multisig.key();
+    proposal_account.proposer = proposer_pubkey;
+
+    // The proposal account is expected to already be funded by the client before
+    // this instruction runs, so assert it landed at a rent-exempt balance rather
+    // than silently leaving a collectible account on-chain.
+    proposal_account.save_rent_exempt(proposal, &Rent::get()?)?;
+
     log!("Proposal initialized successfully!");
     log!("  - Proposal ID: {}", proposal_id);
     log!("  - Status: Active");
@@ -93,7 +109,7 @@ mod tests {
         sysvar,
         clock::Clock,
     };
-    use crate::state::{Multisig, ProposalState, ProposalStatus};
+    use crate::state::{CreditBucket, Multisig, ProposalState, ProposalStatus, VoteDelegate, MAX_CREDIT_HISTORY};
 
     const PROGRAM_ID: Pubkey = pubkey!("3X4xfxBGSWDc24HhACGxk5VdDAJzg9mxtUvvHvwjQcec");
     const CREATOR: Pubkey = Pubkey::new_from_array([1u8; 32]);
@@ -144,6 +160,7 @@ mod tests {
         }
 
         let multisig = Multisig {
+            version: Multisig::CURRENT_VERSION,
             creator: CREATOR.to_bytes(),
             member_count: 3,
             memeber_keys: member_keys,
@@ -152,7 +169,12 @@ mod tests {
             total_proposals: 0,
             treasury_wallet: Pubkey::new_from_array([99u8; 32]).to_bytes(),
             config_bump: 255,
-            treasury_bump: 254
+            treasury_bump: 254,
+            delegates: [VoteDelegate::EMPTY; 10],
+            credit_history: [CreditBucket::EMPTY; MAX_CREDIT_HISTORY],
+            credit_head: 0,
+            credit_len: 0,
+            member_weights: [1u64; 10],
         };
 
         println!("Created multisig with {} members, threshold: {}", multisig.member_count, multisig.threshold);
@@ -173,10 +195,11 @@ mod tests {
             rent_epoch: 0,
         };
 
-        // Create empty proposal account
+        // Create empty proposal account, funded rent-exempt since `init_proposal`
+        // now asserts that via `PackedState::save_rent_exempt`.
         let proposal_account_data = vec![0u8; ProposalState::LEN];
         let proposal_account = Account {
-            lamports: 1_000_000,
+            lamports: solana_sdk::rent::Rent::default().minimum_balance(ProposalState::LEN),
             data: proposal_account_data,
             owner: PROGRAM_ID,
             executable: false,
@@ -219,11 +242,11 @@ mod tests {
 
         println!("Clock sysvar created with timestamp: {}", current_time);
 
-        // Create instruction data
+        // Create instruction data: discriminator byte followed by the shared
+        // `InitProposal` layout.
         let proposal_expiry_duration = 86400u64; // 24 hours
-        let mut instruction_data = vec![0u8; 9];
-        instruction_data[0] = 4; // init_proposal discriminator (adjust based on your program)
-        instruction_data[1..9].copy_from_slice(&proposal_expiry_duration.to_le_bytes());
+        let mut instruction_data = vec![MultisigInstructions::InitializeProposal as u8];
+        instruction_data.extend_from_slice(&InitProposal { expiry_duration: proposal_expiry_duration }.encode());
 
         println!("Instruction data created:");
         println!("  Discriminator: {}", instruction_data[0]);
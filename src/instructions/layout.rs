@@ -0,0 +1,192 @@
+use pinocchio::{program_error::ProgramError, pubkey::Pubkey};
+
+use crate::state::GovernanceActionKind;
+
+/// Instruction discriminators, mirroring the match arms in `process_instruction`.
+/// Shared so the dispatcher and any off-chain client agree on the same byte
+/// for each instruction instead of each side hard-coding its own number.
+#[repr(u8)]
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub enum MultisigInstructions {
+    InitializeMultisig = 0,
+    AddMember = 1,
+    RemoveMember = 2,
+    InitializeProposal = 3,
+    Vote = 4,
+    SetVoteDelegate = 5,
+    RevokeVoteDelegate = 6,
+    SetMemberWeight = 7,
+    SetProposalAction = 8,
+    ExecuteProposal = 9,
+    SetGovernanceAction = 10,
+    CloseProposal = 11,
+}
+
+impl TryFrom<&u8> for MultisigInstructions {
+    type Error = ProgramError;
+
+    fn try_from(value: &u8) -> Result<Self, Self::Error> {
+        match value {
+            0 => Ok(MultisigInstructions::InitializeMultisig),
+            1 => Ok(MultisigInstructions::AddMember),
+            2 => Ok(MultisigInstructions::RemoveMember),
+            3 => Ok(MultisigInstructions::InitializeProposal),
+            4 => Ok(MultisigInstructions::Vote),
+            5 => Ok(MultisigInstructions::SetVoteDelegate),
+            6 => Ok(MultisigInstructions::RevokeVoteDelegate),
+            7 => Ok(MultisigInstructions::SetMemberWeight),
+            8 => Ok(MultisigInstructions::SetProposalAction),
+            9 => Ok(MultisigInstructions::ExecuteProposal),
+            10 => Ok(MultisigInstructions::SetGovernanceAction),
+            11 => Ok(MultisigInstructions::CloseProposal),
+            _ => Err(ProgramError::InvalidInstructionData),
+        }
+    }
+}
+
+/// `InitializeMultisig` payload (discriminator byte already stripped).
+/// Layout: `member_count(8, LE) | threshold(1) | proposal_expiry(8, LE)`.
+pub struct InitMultisig {
+    pub member_count: u64,
+    pub threshold: u8,
+    pub proposal_expiry: u64,
+}
+
+impl InitMultisig {
+    pub const LEN: usize = 17;
+
+    pub fn decode(data: &[u8]) -> Result<Self, ProgramError> {
+        if data.len() != Self::LEN {
+            return Err(ProgramError::InvalidInstructionData);
+        }
+
+        Ok(Self {
+            member_count: u64::from_le_bytes(data[0..8].try_into().unwrap()),
+            threshold: data[8],
+            proposal_expiry: u64::from_le_bytes(data[9..17].try_into().unwrap()),
+        })
+    }
+
+    pub fn encode(&self) -> [u8; Self::LEN] {
+        let mut out = [0u8; Self::LEN];
+        out[0..8].copy_from_slice(&self.member_count.to_le_bytes());
+        out[8] = self.threshold;
+        out[9..17].copy_from_slice(&self.proposal_expiry.to_le_bytes());
+        out
+    }
+}
+
+/// `InitializeProposal` payload. Layout: `expiry_duration(8, LE)`.
+pub struct InitProposal {
+    pub expiry_duration: u64,
+}
+
+impl InitProposal {
+    pub const LEN: usize = 8;
+
+    pub fn decode(data: &[u8]) -> Result<Self, ProgramError> {
+        if data.len() != Self::LEN {
+            return Err(ProgramError::InvalidInstructionData);
+        }
+
+        Ok(Self {
+            expiry_duration: u64::from_le_bytes(data.try_into().unwrap()),
+        })
+    }
+
+    pub fn encode(&self) -> [u8; Self::LEN] {
+        self.expiry_duration.to_le_bytes()
+    }
+}
+
+/// `Vote` payload. Layout: `vote_type(1)`.
+pub struct Vote {
+    pub vote_type: u8,
+}
+
+impl Vote {
+    pub const LEN: usize = 1;
+
+    pub fn decode(data: &[u8]) -> Result<Self, ProgramError> {
+        if data.len() != Self::LEN {
+            return Err(ProgramError::InvalidInstructionData);
+        }
+
+        Ok(Self { vote_type: data[0] })
+    }
+
+    pub fn encode(&self) -> [u8; Self::LEN] {
+        [self.vote_type]
+    }
+}
+
+/// `SetVoteDelegate` payload. Layout: `delegate(32) | valid_until(8, LE)`.
+pub struct SetVoteDelegate {
+    pub delegate: Pubkey,
+    pub valid_until: u64,
+}
+
+impl SetVoteDelegate {
+    pub const LEN: usize = 40;
+
+    pub fn decode(data: &[u8]) -> Result<Self, ProgramError> {
+        if data.len() != Self::LEN {
+            return Err(ProgramError::InvalidInstructionData);
+        }
+
+        let mut delegate = [0u8; 32];
+        delegate.copy_from_slice(&data[0..32]);
+
+        Ok(Self {
+            delegate,
+            valid_until: u64::from_le_bytes(data[32..40].try_into().unwrap()),
+        })
+    }
+
+    pub fn encode(&self) -> [u8; Self::LEN] {
+        let mut out = [0u8; Self::LEN];
+        out[0..32].copy_from_slice(&self.delegate);
+        out[32..40].copy_from_slice(&self.valid_until.to_le_bytes());
+        out
+    }
+}
+
+/// `SetGovernanceAction` payload. Layout: `kind(1) | member(32) | weight(8, LE)`.
+/// `weight` is only meaningful when `kind == GovernanceActionKind::SetMemberWeight`.
+pub struct SetGovernanceAction {
+    pub kind: GovernanceActionKind,
+    pub member: Pubkey,
+    pub weight: u64,
+}
+
+impl SetGovernanceAction {
+    pub const LEN: usize = 41;
+
+    pub fn decode(data: &[u8]) -> Result<Self, ProgramError> {
+        if data.len() != Self::LEN {
+            return Err(ProgramError::InvalidInstructionData);
+        }
+
+        let kind = match data[0] {
+            1 => GovernanceActionKind::AddMember,
+            2 => GovernanceActionKind::RemoveMember,
+            3 => GovernanceActionKind::SetMemberWeight,
+            _ => return Err(ProgramError::InvalidInstructionData),
+        };
+
+        let mut member = [0u8; 32];
+        member.copy_from_slice(&data[1..33]);
+
+        let weight = u64::from_le_bytes(data[33..41].try_into().unwrap());
+
+        Ok(Self { kind, member, weight })
+    }
+
+    pub fn encode(&self) -> [u8; Self::LEN] {
+        let mut out = [0u8; Self::LEN];
+        out[0] = self.kind as u8;
+        out[1..33].copy_from_slice(&self.member);
+        out[33..41].copy_from_slice(&self.weight.to_le_bytes());
+        out
+    }
+}
@@ -1,4 +1,5 @@
-use pinocchio::{program_error::ProgramError, pubkey::Pubkey};
+pub mod layout;
+pub use layout::*;
 
 pub mod init_multisig;
 pub use init_multisig::*;
@@ -15,25 +16,23 @@ pub use init_proposal::*;
 pub mod process_vote;
 pub use process_vote::*;
 
-// pub enum MultisigInstructions {
-//     InitializeMultisig = 0,
-//     AddMember = 1,
-//     RemoveMember = 2,
-//     InitializeProposal = 3,
-//     Vote = 4,
-// }
-
-// impl TryFrom<&u8> for MultisigInstructions {
-//     type Error = ProgramError;
-
-//     fn try_from(value: &u8) -> Result<Self, Self::Error> {
-//         match value {
-//             0 => Ok(MultisigInstructions::InitializeMultisig),
-//             1 => Ok(MultisigInstructions::AddMember),
-//             2 => Ok(MultisigInstructions::RemoveMember),
-//             3 => Ok(MultisigInstructions::InitializeProposal),
-//             4 => Ok(MultisigInstructions::Vote),
-//             _ => Err(ProgramError::InvalidInstructionData)
-//         }
-//     }
-// }
\ No newline at end of file
+pub mod set_vote_delegate;
+pub use set_vote_delegate::*;
+
+pub mod revoke_vote_delegate;
+pub use revoke_vote_delegate::*;
+
+pub mod set_member_weight;
+pub use set_member_weight::*;
+
+pub mod set_proposal_action;
+pub use set_proposal_action::*;
+
+pub mod execute_proposal;
+pub use execute_proposal::*;
+
+pub mod set_governance_action;
+pub use set_governance_action::*;
+
+pub mod close_proposal;
+pub use close_proposal::*;
\ No newline at end of file
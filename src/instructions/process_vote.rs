@@ -8,7 +8,11 @@ use pinocchio::{
 
 use pinocchio_log::log;
 
-use crate::state::{multisig::Multisig, proposal::ProposalState, proposal::ProposalStatus, vote::VoteState};
+use crate::state::{
+    multisig::CreditBucket, multisig::Multisig, multisig::NO_DELEGATE, multisig::MAX_CREDIT_HISTORY,
+    proposal::ProposalState, proposal::ProposalStatus, proposal::VoteChange, proposal::MAX_VOTE_CHANGES,
+    vote::VoteState,
+};
 
 // Vote types
 pub const VOTE_NOT_VOTED: u8 = 0;
@@ -39,6 +43,15 @@ pub fn process_vote(
     let mut proposal_account = ProposalState::from_account_info(proposal)?;
     log!("Proposal loaded with ID: {}", proposal_account.proposal_id);
 
+    // A proposal only carries its votes against the multisig it was created
+    // under; without this check a proposal created under one (e.g. attacker
+    // controlled) multisig could be paired with a different `multisig` account
+    // here, tallying votes against the wrong membership and weights.
+    if proposal_account.multisig != *multisig.key() {
+        log!("Proposal does not belong to this multisig");
+        return Err(ProgramError::InvalidAccountData);
+    }
+
     // Check if proposal is active
     if !matches!(proposal_account.result, ProposalStatus::Active) {
         return Err(ProgramError::InvalidAccountData);
@@ -50,14 +63,17 @@ pub fn process_vote(
     
     if current_time > proposal_account.expiry {
         log!("Proposal has expired. Current time: {}, Expiry: {}", current_time, proposal_account.expiry);
-        // Mark proposal as failed due to expiry
-        proposal_account.result = ProposalStatus::Failed;
-        return Err(ProgramError::InvalidAccountData);
+        // An instruction-level error discards every account mutation the runtime
+        // saw this instruction make, so the `Expired` transition below would
+        // never actually land on-chain if this returned `Err` instead of `Ok`.
+        // Reject the vote via the persisted account state rather than a tx error.
+        proposal_account.result = ProposalStatus::Expired;
+        return Ok(());
     }
 
     log!("Proposal is active and not expired");
 
-    // Verify voter is a member and get their index
+    // Verify voter is a member (directly, or via an unexpired vote delegate) and get their index
     let voter_pubkey = *voter.key();
     let mut voter_index: Option<usize> = None;
 
@@ -68,6 +84,20 @@ pub fn process_vote(
         }
     }
 
+    if voter_index.is_none() {
+        for entry in multisig_account.delegates.iter() {
+            if entry.member_index == NO_DELEGATE {
+                continue;
+            }
+
+            if entry.delegate == voter_pubkey && current_time <= entry.valid_until {
+                log!("Vote cast by delegate on behalf of member {}", entry.member_index as u64);
+                voter_index = Some(entry.member_index as usize);
+                break;
+            }
+        }
+    }
+
     let voter_idx = match voter_index {
         Some(idx) => idx,
         None => {
@@ -83,10 +113,27 @@ pub fn process_vote(
         // You could return an error here if you don't want to allow vote changes
     }
 
-    // Record the vote
     proposal_account.votes[voter_idx] = vote_type;
     log!("Vote recorded: Member {} voted {}", voter_idx, vote_type);
 
+    // Append this mutation to the bounded vote-change audit trail, evicting the
+    // oldest entry once the ring buffer is full.
+    let change_slot = if (proposal_account.change_len as usize) < MAX_VOTE_CHANGES {
+        let idx = (proposal_account.change_head as usize + proposal_account.change_len as usize) % MAX_VOTE_CHANGES;
+        proposal_account.change_len += 1;
+        idx
+    } else {
+        let idx = proposal_account.change_head as usize;
+        proposal_account.change_head = ((proposal_account.change_head as usize + 1) % MAX_VOTE_CHANGES) as u8;
+        idx
+    };
+    proposal_account.vote_changes[change_slot] = VoteChange {
+        voter_index: voter_idx as u8,
+        old_vote: previous_vote,
+        new_vote: vote_type,
+        timestamp: current_time,
+    };
+
     // Update vote account if provided
     if vote_account.data_len() > 0 {
         let mut vote_state = VoteState::from_account_info(vote_account)?;
@@ -95,58 +142,90 @@ pub fn process_vote(
         log!("Updated vote account - vote count: {}", vote_state.vote_count);
     }
 
-    // Count current votes
+    // Recompute `yes_weight`/`no_weight` from scratch against each member's
+    // CURRENT weight, rather than incrementally undoing a prior vote using
+    // today's weight: if a `SetMemberWeight` governance proposal changed a
+    // member's weight after they voted, the incremental undo would subtract
+    // the wrong amount (underflowing or silently drifting the tally) the next
+    // time that member changes their vote.
     let mut votes_for = 0u64;
     let mut votes_against = 0u64;
     let mut votes_abstain = 0u64;
-    let mut total_votes = 0u64;
+    let mut total_weight = 0u64;
 
     for i in 0..multisig_account.member_count as usize {
+        let weight = multisig_account.member_weights[i];
+        total_weight += weight;
+
         match proposal_account.votes[i] {
-            VOTE_FOR => {
-                votes_for += 1;
-                total_votes += 1;
-            },
-            VOTE_AGAINST => {
-                votes_against += 1;
-                total_votes += 1;
-            },
-            VOTE_ABSTAIN => {
-                votes_abstain += 1;
-                total_votes += 1;
-            },
-            VOTE_NOT_VOTED => {},
-            _ => {
-                log!("Invalid vote found at index {}: {}", i, proposal_account.votes[i]);
-            }
+            VOTE_FOR => votes_for += weight,
+            VOTE_AGAINST => votes_against += weight,
+            VOTE_ABSTAIN => votes_abstain += weight,
+            _ => {}
         }
     }
 
-    log!("Vote tally - For: {}, Against: {}, Abstain: {}, Total: {}", 
-         votes_for, votes_against, votes_abstain, total_votes);
+    proposal_account.yes_weight = votes_for;
+    proposal_account.no_weight = votes_against;
+
+    log!("Vote tally (weighted) - For: {}, Against: {}, Abstain: {}, Total weight: {}",
+         votes_for, votes_against, votes_abstain, total_weight);
 
     // Check if proposal should be resolved
     let threshold = multisig_account.threshold;
-    
-    // Proposal succeeds if votes_for >= threshold
+
+    // Proposal passes once weighted yes_weight reaches threshold
     if votes_for >= threshold {
-        proposal_account.result = ProposalStatus::Succeeded;
-        log!("Proposal succeeded! Votes for ({}) >= threshold ({})", votes_for, threshold);
-    }
-    // Proposal fails if it's impossible to reach threshold
-    // (votes_against + remaining_votes < threshold needed)
-    else if votes_against > multisig_account.member_count - threshold {
-        proposal_account.result = ProposalStatus::Failed;
-        log!("Proposal failed! Too many against votes to reach threshold");
+        proposal_account.result = ProposalStatus::Passed;
+        log!("Proposal passed! yes_weight ({}) >= threshold ({})", votes_for, threshold);
+
+        // Credit every member who participated into the current time bucket so
+        // governance tooling can audit participation over time.
+        let bucket = current_time / multisig_account.proposal_expiry.max(1);
+
+        let mut bucket_slot: Option<usize> = None;
+        for i in 0..multisig_account.credit_len as usize {
+            let idx = (multisig_account.credit_head as usize + i) % MAX_CREDIT_HISTORY;
+            if multisig_account.credit_history[idx].bucket == bucket {
+                bucket_slot = Some(idx);
+                break;
+            }
+        }
+
+        let slot = match bucket_slot {
+            Some(idx) => idx,
+            None if (multisig_account.credit_len as usize) < MAX_CREDIT_HISTORY => {
+                let idx = (multisig_account.credit_head as usize + multisig_account.credit_len as usize) % MAX_CREDIT_HISTORY;
+                multisig_account.credit_history[idx] = CreditBucket { bucket, credits: [0u64; 10] };
+                multisig_account.credit_len += 1;
+                idx
+            }
+            None => {
+                // History is full: evict the oldest bucket and advance the head.
+                let idx = multisig_account.credit_head as usize;
+                multisig_account.credit_history[idx] = CreditBucket { bucket, credits: [0u64; 10] };
+                multisig_account.credit_head = ((multisig_account.credit_head as usize + 1) % MAX_CREDIT_HISTORY) as u8;
+                idx
+            }
+        };
+
+        for i in 0..multisig_account.member_count as usize {
+            if proposal_account.votes[i] != VOTE_NOT_VOTED {
+                multisig_account.credit_history[slot].credits[i] += 1;
+            }
+        }
+
+        log!("Accrued voting credits for bucket {}", bucket);
     }
-    // Proposal fails if all members have voted but threshold not met
-    else if total_votes == multisig_account.member_count && votes_for < threshold {
-        proposal_account.result = ProposalStatus::Failed;
-        log!("Proposal failed! All members voted but threshold not reached");
+    // Proposal is rejected once the maximum still-achievable yes_weight (every
+    // not-yet-committed member voting for) can no longer reach threshold.
+    else if total_weight.saturating_sub(votes_against).saturating_sub(votes_abstain) < threshold {
+        proposal_account.result = ProposalStatus::Rejected;
+        log!("Proposal rejected! Remaining achievable weight cannot reach threshold");
     }
     // Otherwise, proposal remains active
     else {
-        log!("Proposal remains active. Need {} more 'for' votes to reach threshold", 
+        log!("Proposal remains active. Need {} more 'for' weight to reach threshold",
              threshold.saturating_sub(votes_for));
     }
 
@@ -168,7 +247,8 @@ mod tests {
         clock::Clock,
     };
     use pinocchio::program_error::ProgramError;
-    use crate::state::{Multisig, ProposalState, ProposalStatus, VoteState};
+    use crate::instructions::layout::MultisigInstructions;
+    use crate::state::{CreditBucket, GovernanceAction, Multisig, ProposalAction, ProposalState, ProposalStatus, VoteChange, VoteDelegate, VoteState, MAX_CREDIT_HISTORY, MAX_VOTE_CHANGES};
 
     const PROGRAM_ID: Pubkey = pubkey!("3X4xfxBGSWDc24HhACGxk5VdDAJzg9mxtUvvHvwjQcec");
     const CREATOR: Pubkey = Pubkey::new_from_array([1u8; 32]);
@@ -207,6 +287,7 @@ mod tests {
         // Create multisig account
         let mut multisig_data = vec![0u8; Multisig::LEN];
         let multisig = Multisig {
+            version: Multisig::CURRENT_VERSION,
             creator: CREATOR.to_bytes(),
             member_count: 3,
             memeber_keys: member_keys,
@@ -215,7 +296,12 @@ mod tests {
             total_proposals: 1,
             treasury_wallet: Pubkey::new_from_array([99u8; 32]).to_bytes(),
             config_bump: 255,
-            treasury_bump: 254
+            treasury_bump: 254,
+            delegates: [VoteDelegate::EMPTY; 10],
+            credit_history: [CreditBucket::EMPTY; MAX_CREDIT_HISTORY],
+            credit_head: 0,
+            credit_len: 0,
+            member_weights: [1u64; 10],
         };
 
         unsafe {
@@ -244,6 +330,7 @@ mod tests {
         active_members[2] = member_3.to_bytes();
         
         let proposal = ProposalState {
+            version: ProposalState::CURRENT_VERSION,
             proposal_id: 0,
             expiry: current_time + 86400,
             result: ProposalStatus::Active,
@@ -251,6 +338,15 @@ mod tests {
             active_members: active_members,
             votes: [0u8; 10], // All NOT_VOTED
             created_time: current_time,
+            vote_changes: [VoteChange::EMPTY; MAX_VOTE_CHANGES],
+            change_head: 0,
+            change_len: 0,
+            action: ProposalAction::EMPTY,
+            yes_weight: 0,
+            no_weight: 0,
+            governance_action: GovernanceAction::NONE,
+            multisig: multisig_pda.to_bytes(),
+            proposer: member_1.to_bytes(),
         };
 
         unsafe {
@@ -273,6 +369,7 @@ mod tests {
         let vote_pda = Pubkey::new_unique();
         let mut vote_data = vec![0u8; VoteState::LEN];
         let vote_state = VoteState {
+            version: VoteState::CURRENT_VERSION,
             has_permission: true,
             vote_count: 0,
             bump: 255,
@@ -328,8 +425,9 @@ mod tests {
             rent_epoch: 0,
         };
 
-        // Test successful vote
-        let instruction_data = vec![1u8]; // Just VOTE_FOR without discriminator
+        // Test successful vote: discriminator byte followed by the shared `Vote` layout
+        let mut instruction_data = vec![MultisigInstructions::Vote as u8];
+        instruction_data.extend_from_slice(&crate::instructions::layout::Vote { vote_type: VOTE_FOR }.encode());
 
         let instruction = Instruction {
             program_id: PROGRAM_ID,
@@ -4,17 +4,12 @@ use pinocchio::{
 
 use pinocchio_log::log;
 
-use crate::state::Multisig;
+use crate::state::{Multisig, VoteDelegate, NO_DELEGATE};
 
 pub fn remove_member(multisig: &AccountInfo, member_to_remove: Pubkey) -> ProgramResult {
 
     let multisig_account = Multisig::from_account_info(multisig)?;
 
-    if multisig_account.member_count <= multisig_account.threshold {
-        log!("Cannot remove member: would make threshold impossible to reach");
-        return Err(ProgramError::InvalidAccountData);
-    };
-
     let mut member_found = false;
     let mut member_index = 0;
 
@@ -31,15 +26,43 @@ pub fn remove_member(multisig: &AccountInfo, member_to_remove: Pubkey) -> Progra
         return Err(ProgramError::InvalidAccountData);
     };
 
+    // `threshold` is a required weight sum: removing this member must not make it
+    // permanently unreachable by the remaining members.
+    let remaining_weight: u64 = (0..multisig_account.member_count as usize)
+        .filter(|&i| i != member_index)
+        .map(|i| multisig_account.member_weights[i])
+        .sum();
+
+    if remaining_weight < multisig_account.threshold {
+        log!("Cannot remove member: would make threshold impossible to reach");
+        return Err(ProgramError::InvalidAccountData);
+    };
+
     // Shift all members after the removed one to fill the gap
     for i in member_index..(multisig_account.member_count as usize - 1) {
         multisig_account.memeber_keys[i] = multisig_account.memeber_keys[i + 1];
+        multisig_account.member_weights[i] = multisig_account.member_weights[i + 1];
     }
 
     // Clear the last slot and decrement count
     multisig_account.memeber_keys[(multisig_account.member_count - 1) as usize] = Pubkey::default();
+    multisig_account.member_weights[(multisig_account.member_count - 1) as usize] = 0;
     multisig_account.member_count -= 1;
-    
+
+    // Drop any delegation referencing the removed member, and shift delegations for
+    // members after it down by one index to track the compacted member array.
+    for entry in multisig_account.delegates.iter_mut() {
+        if entry.member_index == NO_DELEGATE {
+            continue;
+        }
+
+        if entry.member_index as usize == member_index {
+            *entry = VoteDelegate::EMPTY;
+        } else if entry.member_index as usize > member_index {
+            entry.member_index -= 1;
+        }
+    }
+
     log!("Successfully removed member!");
     log!("New member count: {}", multisig_account.member_count);
 
@@ -52,111 +75,206 @@ pub fn remove_member(multisig: &AccountInfo, member_to_remove: Pubkey) -> Progra
 #[cfg(test)]
 mod tests {
     use super::*;
-    use mollusk_svm::{program, Mollusk, result::Check};
+    use crate::instructions::layout::MultisigInstructions;
+    use crate::state::{
+        CreditBucket, GovernanceAction, GovernanceActionKind, ProposalAction, ProposalState,
+        ProposalStatus, VoteChange, MAX_CREDIT_HISTORY, MAX_VOTE_CHANGES,
+    };
+    use mollusk_svm::{Mollusk, result::Check};
     use solana_sdk::{
         account::Account,
         instruction::{AccountMeta, Instruction},
         pubkey::Pubkey,
         pubkey,
+        sysvar,
+        clock::Clock as SdkClock,
     };
 
     const PROGRAM_ID: Pubkey = pubkey!("3X4xfxBGSWDc24HhACGxk5VdDAJzg9mxtUvvHvwjQcec");
     const CREATOR: Pubkey = Pubkey::new_from_array([1u8; 32]);
 
+    /// Membership changes now only apply through a passed proposal's execute
+    /// step, so this exercises `execute_proposal` with a `RemoveMember`
+    /// governance action rather than calling `remove_member` directly.
     #[test]
-    fn test_remove_member() {
-        println!("Starting remove member test");
-
+    fn test_remove_member_via_execute_proposal() {
         let mollusk = Mollusk::new(&PROGRAM_ID, "target/deploy/multisig_pinocchio");
-        println!("Mollusk initialized with program ID: {}", PROGRAM_ID);
 
-        let (multisig_pda, bump1) = Pubkey::find_program_address(
+        let (multisig_pda, _) = Pubkey::find_program_address(
             &[b"multisig", CREATOR.as_ref()],
             &PROGRAM_ID
         );
 
-        println!("Multisig PDA: {} (bump: {})", multisig_pda, bump1);
+        let (treasury_pda, treasury_bump) = Pubkey::find_program_address(
+            &[b"treasury", multisig_pda.as_ref()],
+            &PROGRAM_ID
+        );
+
+        let proposal_id = 0u64;
+        let (proposal_pda, _) = Pubkey::find_program_address(
+            &[b"proposal", multisig_pda.as_ref(), &proposal_id.to_le_bytes()],
+            &PROGRAM_ID
+        );
 
         let member_to_remove = Pubkey::new_from_array([10u8; 32]);
         let member_1 = Pubkey::new_from_array([20u8; 32]);
         let member_2 = Pubkey::new_from_array([30u8; 32]);
-        
+
         let mut initial_members = [Pubkey::default(); 10];
         initial_members[0] = member_to_remove;
         initial_members[1] = member_1;
         initial_members[2] = member_2;
 
-        println!("Initial members:");
-        println!("  Member 0 (to remove): {}", member_to_remove);
-        println!("  Member 1: {}", member_1);
-        println!("  Member 2: {}", member_2);
-
-        let mut account_data = vec![0u8; Multisig::LEN];
         let mut member_keys = [[0u8; 32]; 10];
         for (i, pk) in initial_members.iter().enumerate() {
             member_keys[i] = pk.to_bytes();
         };
 
         let multisig = Multisig {
+            version: Multisig::CURRENT_VERSION,
             creator: CREATOR.to_bytes(),
             member_count: 3, // 3 members with threshold 2
             memeber_keys: member_keys,
             threshold: 2,
             proposal_expiry: 86400,
-            total_proposals: 0,
-            treasury_wallet: Pubkey::new_from_array([99u8; 32]).to_bytes(),
+            total_proposals: 1,
+            treasury_wallet: treasury_pda.to_bytes(),
             config_bump: 255,
-            treasury_bump: 254
+            treasury_bump,
+            delegates: [VoteDelegate::EMPTY; 10],
+            credit_history: [CreditBucket::EMPTY; MAX_CREDIT_HISTORY],
+            credit_head: 0,
+            credit_len: 0,
+            member_weights: [1u64; 10],
         };
 
-        println!("Created multisig with {} members, threshold: {}", multisig.member_count, multisig.threshold);
-
+        let mut multisig_data = vec![0u8; Multisig::LEN];
         unsafe {
             std::ptr::copy_nonoverlapping(
                 &multisig as *const Multisig as *const u8,
-                account_data.as_mut_ptr(),
+                multisig_data.as_mut_ptr(),
                 Multisig::LEN,
             );
         };
 
         let multisig_account = Account {
             lamports: 1_000_000,
-            data: account_data,
+            data: multisig_data,
+            owner: PROGRAM_ID,
+            executable: false,
+            rent_epoch: 0,
+        };
+
+        let current_time = 1_640_995_200u64;
+
+        let mut active_members = [[0u8; 32]; 10];
+        active_members[0] = member_to_remove.to_bytes();
+        active_members[1] = member_1.to_bytes();
+        active_members[2] = member_2.to_bytes();
+
+        let proposal = ProposalState {
+            version: ProposalState::CURRENT_VERSION,
+            proposal_id,
+            expiry: current_time + 86400,
+            result: ProposalStatus::Passed,
+            bump: 255,
+            active_members,
+            votes: [0u8; 10],
+            created_time: current_time,
+            vote_changes: [VoteChange::EMPTY; MAX_VOTE_CHANGES],
+            change_head: 0,
+            change_len: 0,
+            action: ProposalAction::EMPTY,
+            yes_weight: 0,
+            no_weight: 0,
+            governance_action: GovernanceAction {
+                kind: GovernanceActionKind::RemoveMember,
+                member: member_to_remove.to_bytes(),
+                weight: 0,
+            },
+            multisig: multisig_pda.to_bytes(),
+            proposer: member_1.to_bytes(),
+        };
+
+        let mut proposal_data = vec![0u8; ProposalState::LEN];
+        unsafe {
+            std::ptr::copy_nonoverlapping(
+                &proposal as *const ProposalState as *const u8,
+                proposal_data.as_mut_ptr(),
+                ProposalState::LEN,
+            );
+        };
+
+        let proposal_account = Account {
+            lamports: 1_000_000,
+            data: proposal_data,
             owner: PROGRAM_ID,
             executable: false,
             rent_epoch: 0,
         };
 
-        println!("Multisig account created with {} lamports", multisig_account.lamports);
+        let treasury_account = Account {
+            lamports: 1_000_000,
+            data: vec![],
+            owner: solana_sdk::system_program::id(),
+            executable: false,
+            rent_epoch: 0,
+        };
+
+        let target_program = Pubkey::new_from_array([88u8; 32]);
+        let target_program_account = Account {
+            lamports: 1_000_000,
+            data: vec![],
+            owner: solana_sdk::bpf_loader::id(),
+            executable: true,
+            rent_epoch: 0,
+        };
 
-        let mut instruction_data = vec![0u8; 33];
-        instruction_data[0] = 3; // remove_member discriminator
-        instruction_data[1..33].copy_from_slice(&member_to_remove.to_bytes());
+        let clock = SdkClock {
+            slot: 1000,
+            epoch_start_timestamp: current_time as i64 - 3600,
+            epoch: 10,
+            leader_schedule_epoch: 10,
+            unix_timestamp: current_time as i64,
+        };
+
+        let clock_data = unsafe {
+            std::slice::from_raw_parts(
+                &clock as *const SdkClock as *const u8,
+                std::mem::size_of::<SdkClock>(),
+            ).to_vec()
+        };
 
-        println!("Instruction data created:");
-        println!("  Discriminator: {}", instruction_data[0]);
-        println!("  Member to remove: {}", member_to_remove);
+        let clock_account = Account {
+            lamports: 1,
+            data: clock_data,
+            owner: sysvar::id(),
+            executable: false,
+            rent_epoch: 0,
+        };
 
         let instruction = Instruction {
             program_id: PROGRAM_ID,
-            accounts: vec![AccountMeta::new(multisig_pda, false)],
-            data: instruction_data,
+            accounts: vec![
+                AccountMeta::new(multisig_pda, false),
+                AccountMeta::new(proposal_pda, false),
+                AccountMeta::new_readonly(treasury_pda, false),
+                AccountMeta::new_readonly(target_program, false),
+                AccountMeta::new_readonly(sysvar::clock::id(), false),
+            ],
+            data: vec![MultisigInstructions::ExecuteProposal as u8],
         };
 
-        println!("Executing remove_member instruction...");
-
         mollusk.process_and_validate_instruction(
             &instruction,
-            &vec![(multisig_pda, multisig_account)],
+            &vec![
+                (multisig_pda, multisig_account),
+                (proposal_pda, proposal_account),
+                (treasury_pda, treasury_account),
+                (target_program, target_program_account),
+                (sysvar::clock::id(), clock_account),
+            ],
             &[Check::success()],
         );
-
-        println!("Instruction executed successfully!");
-        println!("Member {} should now be removed from multisig", member_to_remove);
-        println!("Members: {:?}", multisig.memeber_keys);
-        println!("Members: {}", multisig.member_count);
-        println!("Remove member test completed successfully!");
-
     }
-
 }
\ No newline at end of file
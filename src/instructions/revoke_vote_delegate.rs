@@ -0,0 +1,46 @@
+use pinocchio::{
+    account_info::AccountInfo, ProgramResult, program_error::ProgramError
+};
+
+use pinocchio_log::log;
+
+use crate::state::{Multisig, VoteDelegate};
+
+pub fn revoke_vote_delegate(multisig: &AccountInfo, member: &AccountInfo) -> ProgramResult {
+
+    if !member.is_signer() {
+        log!("Member must sign to revoke a vote delegate");
+        return Err(ProgramError::MissingRequiredSignature);
+    }
+
+    let multisig_account = Multisig::from_account_info(multisig)?;
+
+    let member_pubkey = *member.key();
+    let mut member_index: Option<usize> = None;
+
+    for i in 0..multisig_account.member_count as usize {
+        if multisig_account.memeber_keys[i] == member_pubkey {
+            member_index = Some(i);
+            break;
+        }
+    }
+
+    let member_index = match member_index {
+        Some(idx) => idx,
+        None => {
+            log!("Signer is not a member of the multisig");
+            return Err(ProgramError::InvalidAccountData);
+        }
+    };
+
+    for entry in multisig_account.delegates.iter_mut() {
+        if entry.member_index as usize == member_index {
+            *entry = VoteDelegate::EMPTY;
+            log!("Vote delegate revoked for member {}", member_index as u64);
+            return Ok(());
+        }
+    }
+
+    log!("No active vote delegate found for member {}", member_index as u64);
+    Err(ProgramError::InvalidAccountData)
+}
@@ -0,0 +1,230 @@
+use pinocchio::{
+    account_info::AccountInfo, pubkey::Pubkey, ProgramResult, program_error::ProgramError
+};
+
+use pinocchio_log::log;
+
+use crate::state::{GovernanceAction, GovernanceActionKind, Multisig, ProposalState, ProposalStatus};
+use super::process_vote::VOTE_NOT_VOTED;
+
+/// Configures the membership or weight change an already-created proposal will
+/// apply on success, gating `add_member`/`remove_member`/`set_member_weight`
+/// behind a passed proposal instead of letting an arbitrary signer call them
+/// directly. `weight` is only meaningful for `GovernanceActionKind::SetMemberWeight`.
+pub fn set_governance_action(
+    multisig: &AccountInfo,
+    proposal: &AccountInfo,
+    proposer: &AccountInfo,
+    kind: GovernanceActionKind,
+    member: Pubkey,
+    weight: u64,
+) -> ProgramResult {
+
+    if !proposer.is_signer() {
+        log!("Proposer must sign to configure a proposal's governance action");
+        return Err(ProgramError::MissingRequiredSignature);
+    }
+
+    let multisig_account = Multisig::from_account_info(multisig)?;
+    let proposal_account = ProposalState::from_account_info(proposal)?;
+
+    // A proposal only belongs to the multisig it was created under; without
+    // this check an attacker's own proposal could be configured here and later
+    // executed against a victim's real multisig/treasury.
+    if proposal_account.multisig != *multisig.key() {
+        log!("Proposal does not belong to this multisig");
+        return Err(ProgramError::InvalidAccountData);
+    }
+
+    if !matches!(proposal_account.result, ProposalStatus::Active) {
+        log!("Proposal is not active; cannot configure its governance action");
+        return Err(ProgramError::InvalidAccountData);
+    }
+
+    let proposer_pubkey = *proposer.key();
+    let mut is_member = false;
+    for i in 0..multisig_account.member_count as usize {
+        if multisig_account.memeber_keys[i] == proposer_pubkey {
+            is_member = true;
+            break;
+        }
+    }
+
+    if !is_member {
+        log!("Only a multisig member may configure a proposal's governance action");
+        return Err(ProgramError::InvalidAccountData);
+    }
+
+    // Immutable once set, for the same reason as `set_proposal_action`: a member
+    // could otherwise canvass "yes" votes for one governance action (e.g. adding
+    // their own key) and swap in a different one before execution, since cast
+    // votes are never re-validated against a later payload.
+    if proposal_account.governance_action.kind != GovernanceActionKind::None {
+        log!("Proposal governance action is already configured and cannot be changed");
+        return Err(ProgramError::InvalidAccountData);
+    }
+
+    // Also require this to run before any vote is cast, so a voter's "yes"
+    // always reflects the governance action they saw at the time they voted.
+    if proposal_account.votes.iter().any(|&v| v != VOTE_NOT_VOTED) {
+        log!("Cannot configure a proposal's governance action after votes have been cast");
+        return Err(ProgramError::InvalidAccountData);
+    }
+
+    proposal_account.governance_action = GovernanceAction { kind, member, weight };
+
+    log!("Proposal governance action configured");
+
+    Ok(())
+}
+
+// -------------------------- TESTING set_governance_action -----------------------------
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::instructions::layout::MultisigInstructions;
+    use crate::state::{CreditBucket, ProposalAction, VoteChange, VoteDelegate, MAX_CREDIT_HISTORY, MAX_VOTE_CHANGES};
+    use mollusk_svm::{Mollusk, result::Check};
+    use solana_sdk::{
+        account::Account,
+        instruction::{AccountMeta, Instruction},
+        pubkey::Pubkey as SdkPubkey,
+        pubkey,
+    };
+
+    const PROGRAM_ID: SdkPubkey = pubkey!("3X4xfxBGSWDc24HhACGxk5VdDAJzg9mxtUvvHvwjQcec");
+    const CREATOR: SdkPubkey = SdkPubkey::new_from_array([1u8; 32]);
+
+    #[test]
+    fn test_set_governance_action_add_member() {
+        let mollusk = Mollusk::new(&PROGRAM_ID, "target/deploy/multisig_pinocchio");
+
+        let (multisig_pda, _) = SdkPubkey::find_program_address(
+            &[b"multisig", CREATOR.as_ref()],
+            &PROGRAM_ID
+        );
+
+        let proposal_id = 0u64;
+        let (proposal_pda, _) = SdkPubkey::find_program_address(
+            &[b"proposal", multisig_pda.as_ref(), &proposal_id.to_le_bytes()],
+            &PROGRAM_ID
+        );
+
+        let member_1 = SdkPubkey::new_from_array([10u8; 32]);
+        let mut member_keys = [[0u8; 32]; 10];
+        member_keys[0] = member_1.to_bytes();
+
+        let multisig = Multisig {
+            version: Multisig::CURRENT_VERSION,
+            creator: CREATOR.to_bytes(),
+            member_count: 1,
+            memeber_keys: member_keys,
+            threshold: 1,
+            proposal_expiry: 86400,
+            total_proposals: 1,
+            treasury_wallet: SdkPubkey::new_from_array([99u8; 32]).to_bytes(),
+            config_bump: 255,
+            treasury_bump: 254,
+            delegates: [VoteDelegate::EMPTY; 10],
+            credit_history: [CreditBucket::EMPTY; MAX_CREDIT_HISTORY],
+            credit_head: 0,
+            credit_len: 0,
+            member_weights: [1u64; 10],
+        };
+
+        let mut multisig_data = vec![0u8; Multisig::LEN];
+        unsafe {
+            std::ptr::copy_nonoverlapping(
+                &multisig as *const Multisig as *const u8,
+                multisig_data.as_mut_ptr(),
+                Multisig::LEN,
+            );
+        }
+
+        let multisig_account = Account {
+            lamports: 1_000_000,
+            data: multisig_data,
+            owner: PROGRAM_ID,
+            executable: false,
+            rent_epoch: 0,
+        };
+
+        let mut active_members = [[0u8; 32]; 10];
+        active_members[0] = member_1.to_bytes();
+
+        let proposal = ProposalState {
+            version: ProposalState::CURRENT_VERSION,
+            proposal_id: 0,
+            expiry: 2_000_000_000,
+            result: ProposalStatus::Active,
+            bump: 255,
+            active_members,
+            votes: [0u8; 10],
+            created_time: 1_900_000_000,
+            vote_changes: [VoteChange::EMPTY; MAX_VOTE_CHANGES],
+            change_head: 0,
+            change_len: 0,
+            action: ProposalAction::EMPTY,
+            yes_weight: 0,
+            no_weight: 0,
+            governance_action: GovernanceAction::NONE,
+            multisig: multisig_pda.to_bytes(),
+            proposer: member_1.to_bytes(),
+        };
+
+        let mut proposal_data = vec![0u8; ProposalState::LEN];
+        unsafe {
+            std::ptr::copy_nonoverlapping(
+                &proposal as *const ProposalState as *const u8,
+                proposal_data.as_mut_ptr(),
+                ProposalState::LEN,
+            );
+        }
+
+        let proposal_account = Account {
+            lamports: 1_000_000,
+            data: proposal_data,
+            owner: PROGRAM_ID,
+            executable: false,
+            rent_epoch: 0,
+        };
+
+        let proposer_account = Account {
+            lamports: 1_000_000,
+            data: vec![],
+            owner: solana_sdk::system_program::id(),
+            executable: false,
+            rent_epoch: 0,
+        };
+
+        let new_member = SdkPubkey::new_from_array([77u8; 32]);
+
+        let mut instruction_data = vec![MultisigInstructions::SetGovernanceAction as u8];
+        instruction_data.extend_from_slice(&crate::instructions::layout::SetGovernanceAction {
+            kind: GovernanceActionKind::AddMember,
+            member: new_member.to_bytes(),
+            weight: 0,
+        }.encode());
+
+        let instruction = Instruction {
+            program_id: PROGRAM_ID,
+            accounts: vec![
+                AccountMeta::new(multisig_pda, false),
+                AccountMeta::new(proposal_pda, false),
+                AccountMeta::new_readonly(member_1, true),
+            ],
+            data: instruction_data,
+        };
+
+        mollusk.process_and_validate_instruction(
+            &instruction,
+            &vec![
+                (multisig_pda, multisig_account),
+                (proposal_pda, proposal_account),
+                (member_1, proposer_account),
+            ],
+            &[Check::success()],
+        );
+    }
+}
@@ -0,0 +1,253 @@
+use pinocchio::{
+    account_info::AccountInfo, pubkey::Pubkey, ProgramResult, program_error::ProgramError
+};
+
+use pinocchio_log::log;
+
+use crate::state::Multisig;
+
+/// Applies a member's weight change. Like `add_member`/`remove_member`, this is
+/// only ever called from `execute_proposal` once a `SetMemberWeight` governance
+/// action has passed — it is not a directly callable instruction, since an
+/// unauthenticated caller could otherwise zero out every other member's voting
+/// power (or inflate their own) and bypass `threshold` entirely.
+pub fn set_member_weight(multisig: &AccountInfo, member: Pubkey, new_weight: u64) -> ProgramResult {
+
+    let multisig_account = Multisig::from_account_info(multisig)?;
+
+    let mut member_index: Option<usize> = None;
+
+    for i in 0..multisig_account.member_count as usize {
+        if multisig_account.memeber_keys[i] == member {
+            member_index = Some(i);
+            break;
+        }
+    }
+
+    let member_index = match member_index {
+        Some(idx) => idx,
+        None => {
+            log!("Member not found in multisig");
+            return Err(ProgramError::InvalidAccountData);
+        }
+    };
+
+    // Reject a weight change that would make `threshold` permanently unreachable,
+    // mirroring the guard in `remove_member`.
+    let other_weight: u64 = (0..multisig_account.member_count as usize)
+        .filter(|&i| i != member_index)
+        .map(|i| multisig_account.member_weights[i])
+        .sum();
+
+    if other_weight + new_weight < multisig_account.threshold {
+        log!("Cannot set member weight: would make threshold impossible to reach");
+        return Err(ProgramError::InvalidAccountData);
+    }
+
+    multisig_account.member_weights[member_index] = new_weight;
+
+    log!("Member {} weight set to {}", member_index as u64, new_weight);
+
+    Ok(())
+}
+
+// -------------------------- TESTING set_member_weight -----------------------------
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::instructions::layout::MultisigInstructions;
+    use crate::state::{
+        CreditBucket, GovernanceAction, GovernanceActionKind, ProposalAction, ProposalState,
+        ProposalStatus, VoteChange, VoteDelegate, MAX_CREDIT_HISTORY, MAX_VOTE_CHANGES,
+    };
+    use mollusk_svm::{Mollusk, result::Check};
+    use solana_sdk::{
+        account::Account,
+        instruction::{AccountMeta, Instruction},
+        pubkey::Pubkey,
+        pubkey,
+        sysvar,
+        clock::Clock as SdkClock,
+    };
+
+    const PROGRAM_ID: Pubkey = pubkey!("3X4xfxBGSWDc24HhACGxk5VdDAJzg9mxtUvvHvwjQcec");
+    const CREATOR: Pubkey = Pubkey::new_from_array([1u8; 32]);
+
+    /// Weight changes now only apply through a passed proposal's execute step,
+    /// so this exercises `execute_proposal` with a `SetMemberWeight` governance
+    /// action rather than calling `set_member_weight` directly.
+    #[test]
+    fn test_set_member_weight_via_execute_proposal() {
+        let mollusk = Mollusk::new(&PROGRAM_ID, "target/deploy/multisig_pinocchio");
+
+        let (multisig_pda, _) = Pubkey::find_program_address(
+            &[b"multisig", CREATOR.as_ref()],
+            &PROGRAM_ID
+        );
+
+        let (treasury_pda, treasury_bump) = Pubkey::find_program_address(
+            &[b"treasury", multisig_pda.as_ref()],
+            &PROGRAM_ID
+        );
+
+        let proposal_id = 0u64;
+        let (proposal_pda, _) = Pubkey::find_program_address(
+            &[b"proposal", multisig_pda.as_ref(), &proposal_id.to_le_bytes()],
+            &PROGRAM_ID
+        );
+
+        let member_1 = Pubkey::new_from_array([10u8; 32]);
+        let member_2 = Pubkey::new_from_array([20u8; 32]);
+
+        let mut member_keys = [[0u8; 32]; 10];
+        member_keys[0] = member_1.to_bytes();
+        member_keys[1] = member_2.to_bytes();
+
+        let multisig = Multisig {
+            version: Multisig::CURRENT_VERSION,
+            creator: CREATOR.to_bytes(),
+            member_count: 2,
+            memeber_keys: member_keys,
+            threshold: 2,
+            proposal_expiry: 86400,
+            total_proposals: 1,
+            treasury_wallet: treasury_pda.to_bytes(),
+            config_bump: 255,
+            treasury_bump,
+            delegates: [VoteDelegate::EMPTY; 10],
+            credit_history: [CreditBucket::EMPTY; MAX_CREDIT_HISTORY],
+            credit_head: 0,
+            credit_len: 0,
+            member_weights: [1u64; 10],
+        };
+
+        let mut multisig_data = vec![0u8; Multisig::LEN];
+        unsafe {
+            std::ptr::copy_nonoverlapping(
+                &multisig as *const Multisig as *const u8,
+                multisig_data.as_mut_ptr(),
+                Multisig::LEN,
+            );
+        }
+
+        let multisig_account = Account {
+            lamports: 1_000_000,
+            data: multisig_data,
+            owner: PROGRAM_ID,
+            executable: false,
+            rent_epoch: 0,
+        };
+
+        let current_time = 1_640_995_200u64;
+
+        let mut active_members = [[0u8; 32]; 10];
+        active_members[0] = member_1.to_bytes();
+        active_members[1] = member_2.to_bytes();
+
+        let proposal = ProposalState {
+            version: ProposalState::CURRENT_VERSION,
+            proposal_id,
+            expiry: current_time + 86400,
+            result: ProposalStatus::Passed,
+            bump: 255,
+            active_members,
+            votes: [0u8; 10],
+            created_time: current_time,
+            vote_changes: [VoteChange::EMPTY; MAX_VOTE_CHANGES],
+            change_head: 0,
+            change_len: 0,
+            action: ProposalAction::EMPTY,
+            yes_weight: 0,
+            no_weight: 0,
+            governance_action: GovernanceAction {
+                kind: GovernanceActionKind::SetMemberWeight,
+                member: member_1.to_bytes(),
+                weight: 3,
+            },
+            multisig: multisig_pda.to_bytes(),
+            proposer: member_1.to_bytes(),
+        };
+
+        let mut proposal_data = vec![0u8; ProposalState::LEN];
+        unsafe {
+            std::ptr::copy_nonoverlapping(
+                &proposal as *const ProposalState as *const u8,
+                proposal_data.as_mut_ptr(),
+                ProposalState::LEN,
+            );
+        }
+
+        let proposal_account = Account {
+            lamports: 1_000_000,
+            data: proposal_data,
+            owner: PROGRAM_ID,
+            executable: false,
+            rent_epoch: 0,
+        };
+
+        let treasury_account = Account {
+            lamports: 1_000_000,
+            data: vec![],
+            owner: solana_sdk::system_program::id(),
+            executable: false,
+            rent_epoch: 0,
+        };
+
+        let clock = SdkClock {
+            slot: 1000,
+            epoch_start_timestamp: current_time as i64 - 3600,
+            epoch: 10,
+            leader_schedule_epoch: 10,
+            unix_timestamp: current_time as i64,
+        };
+
+        let clock_data = unsafe {
+            std::slice::from_raw_parts(
+                &clock as *const SdkClock as *const u8,
+                std::mem::size_of::<SdkClock>(),
+            ).to_vec()
+        };
+
+        let clock_account = Account {
+            lamports: 1,
+            data: clock_data,
+            owner: sysvar::id(),
+            executable: false,
+            rent_epoch: 0,
+        };
+
+        let target_program = Pubkey::new_from_array([88u8; 32]);
+        let target_program_account = Account {
+            lamports: 1_000_000,
+            data: vec![],
+            owner: solana_sdk::bpf_loader::id(),
+            executable: true,
+            rent_epoch: 0,
+        };
+
+        let instruction = Instruction {
+            program_id: PROGRAM_ID,
+            accounts: vec![
+                AccountMeta::new(multisig_pda, false),
+                AccountMeta::new(proposal_pda, false),
+                AccountMeta::new_readonly(treasury_pda, false),
+                AccountMeta::new_readonly(target_program, false),
+                AccountMeta::new_readonly(sysvar::clock::id(), false),
+            ],
+            data: vec![MultisigInstructions::ExecuteProposal as u8],
+        };
+
+        mollusk.process_and_validate_instruction(
+            &instruction,
+            &vec![
+                (multisig_pda, multisig_account),
+                (proposal_pda, proposal_account),
+                (treasury_pda, treasury_account),
+                (target_program, target_program_account),
+                (sysvar::clock::id(), clock_account),
+            ],
+            &[Check::success()],
+        );
+    }
+}
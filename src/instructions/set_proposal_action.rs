@@ -0,0 +1,284 @@
+use pinocchio::{
+    account_info::AccountInfo, pubkey::Pubkey, ProgramResult, program_error::ProgramError
+};
+
+use pinocchio_log::log;
+
+use crate::state::{ActionAccountMeta, Multisig, ProposalAction, ProposalState, ProposalStatus, MAX_ACTION_ACCOUNTS, MAX_ACTION_DATA};
+use super::process_vote::VOTE_NOT_VOTED;
+
+/// Configures the CPI action an already-created proposal will perform on success.
+/// `action_data` layout: `program_id(32) | accounts_len(1) | accounts_len * (pubkey(32) | flags(1)) | data_len(2, LE) | data_len bytes`.
+/// `flags` bit 0 is `is_signer`, bit 1 is `is_writable`.
+pub fn set_proposal_action(
+    multisig: &AccountInfo,
+    proposal: &AccountInfo,
+    proposer: &AccountInfo,
+    action_data: &[u8],
+) -> ProgramResult {
+
+    if !proposer.is_signer() {
+        log!("Proposer must sign to configure a proposal action");
+        return Err(ProgramError::MissingRequiredSignature);
+    }
+
+    let multisig_account = Multisig::from_account_info(multisig)?;
+    let proposal_account = ProposalState::from_account_info(proposal)?;
+
+    // A proposal only belongs to the multisig it was created under; without
+    // this check an attacker's own proposal could be configured here and later
+    // executed against a victim's real multisig/treasury.
+    if proposal_account.multisig != *multisig.key() {
+        log!("Proposal does not belong to this multisig");
+        return Err(ProgramError::InvalidAccountData);
+    }
+
+    if !matches!(proposal_account.result, ProposalStatus::Active) {
+        log!("Proposal is not active; cannot configure its action");
+        return Err(ProgramError::InvalidAccountData);
+    }
+
+    let proposer_pubkey = *proposer.key();
+    let mut is_member = false;
+    for i in 0..multisig_account.member_count as usize {
+        if multisig_account.memeber_keys[i] == proposer_pubkey {
+            is_member = true;
+            break;
+        }
+    }
+
+    if !is_member {
+        log!("Only a multisig member may configure a proposal's action");
+        return Err(ProgramError::InvalidAccountData);
+    }
+
+    // Immutable once set: a member could otherwise canvass "yes" votes for one
+    // advertised action and swap in a different CPI target before execution,
+    // since cast votes are never re-validated against a later action payload.
+    if proposal_account.action.has_action {
+        log!("Proposal action is already configured and cannot be changed");
+        return Err(ProgramError::InvalidAccountData);
+    }
+
+    // Also require this to run before any vote is cast, so a voter's "yes"
+    // always reflects the action they saw at the time they voted.
+    if proposal_account.votes.iter().any(|&v| v != VOTE_NOT_VOTED) {
+        log!("Cannot configure a proposal's action after votes have been cast");
+        return Err(ProgramError::InvalidAccountData);
+    }
+
+    if action_data.len() < 33 {
+        return Err(ProgramError::InvalidInstructionData);
+    }
+
+    let mut program_id = [0u8; 32];
+    program_id.copy_from_slice(&action_data[0..32]);
+
+    let accounts_len = action_data[32] as usize;
+    if accounts_len > MAX_ACTION_ACCOUNTS {
+        log!("Action references too many accounts");
+        return Err(ProgramError::InvalidInstructionData);
+    }
+
+    let mut accounts = [ActionAccountMeta::EMPTY; MAX_ACTION_ACCOUNTS];
+    let mut cursor = 33usize;
+
+    for account in accounts.iter_mut().take(accounts_len) {
+        if action_data.len() < cursor + 33 {
+            return Err(ProgramError::InvalidInstructionData);
+        }
+
+        let mut pubkey: Pubkey = [0u8; 32];
+        pubkey.copy_from_slice(&action_data[cursor..cursor + 32]);
+        let flags = action_data[cursor + 32];
+
+        *account = ActionAccountMeta {
+            pubkey,
+            is_signer: flags & 0b01 != 0,
+            is_writable: flags & 0b10 != 0,
+        };
+
+        cursor += 33;
+    }
+
+    if action_data.len() < cursor + 2 {
+        return Err(ProgramError::InvalidInstructionData);
+    }
+
+    let data_len = u16::from_le_bytes([action_data[cursor], action_data[cursor + 1]]) as usize;
+    cursor += 2;
+
+    if data_len > MAX_ACTION_DATA || action_data.len() < cursor + data_len {
+        log!("Action instruction data too large or truncated");
+        return Err(ProgramError::InvalidInstructionData);
+    }
+
+    let mut data = [0u8; MAX_ACTION_DATA];
+    data[..data_len].copy_from_slice(&action_data[cursor..cursor + data_len]);
+
+    proposal_account.action = ProposalAction {
+        program_id,
+        accounts,
+        accounts_len: accounts_len as u8,
+        data,
+        data_len: data_len as u16,
+        has_action: true,
+    };
+
+    log!("Proposal action configured: {} accounts, {} data bytes", accounts_len as u64, data_len as u64);
+
+    Ok(())
+}
+
+// -------------------------- TESTING set_proposal_action -----------------------------
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::state::{CreditBucket, GovernanceAction, VoteChange, VoteDelegate, MAX_CREDIT_HISTORY, MAX_VOTE_CHANGES};
+    use mollusk_svm::{Mollusk, result::Check};
+    use solana_sdk::{
+        account::Account,
+        instruction::{AccountMeta, Instruction},
+        pubkey::Pubkey as SdkPubkey,
+        pubkey,
+    };
+
+    const PROGRAM_ID: SdkPubkey = pubkey!("3X4xfxBGSWDc24HhACGxk5VdDAJzg9mxtUvvHvwjQcec");
+    const CREATOR: SdkPubkey = SdkPubkey::new_from_array([1u8; 32]);
+
+    #[test]
+    fn test_set_proposal_action() {
+        let mollusk = Mollusk::new(&PROGRAM_ID, "target/deploy/multisig_pinocchio");
+
+        let (multisig_pda, _) = SdkPubkey::find_program_address(
+            &[b"multisig", CREATOR.as_ref()],
+            &PROGRAM_ID
+        );
+
+        let proposal_id = 0u64;
+        let (proposal_pda, _) = SdkPubkey::find_program_address(
+            &[b"proposal", multisig_pda.as_ref(), &proposal_id.to_le_bytes()],
+            &PROGRAM_ID
+        );
+
+        let member_1 = SdkPubkey::new_from_array([10u8; 32]);
+        let mut member_keys = [[0u8; 32]; 10];
+        member_keys[0] = member_1.to_bytes();
+
+        let multisig = Multisig {
+            version: Multisig::CURRENT_VERSION,
+            creator: CREATOR.to_bytes(),
+            member_count: 1,
+            memeber_keys: member_keys,
+            threshold: 1,
+            proposal_expiry: 86400,
+            total_proposals: 1,
+            treasury_wallet: SdkPubkey::new_from_array([99u8; 32]).to_bytes(),
+            config_bump: 255,
+            treasury_bump: 254,
+            delegates: [VoteDelegate::EMPTY; 10],
+            credit_history: [CreditBucket::EMPTY; MAX_CREDIT_HISTORY],
+            credit_head: 0,
+            credit_len: 0,
+            member_weights: [1u64; 10],
+        };
+
+        let mut multisig_data = vec![0u8; Multisig::LEN];
+        unsafe {
+            std::ptr::copy_nonoverlapping(
+                &multisig as *const Multisig as *const u8,
+                multisig_data.as_mut_ptr(),
+                Multisig::LEN,
+            );
+        }
+
+        let multisig_account = Account {
+            lamports: 1_000_000,
+            data: multisig_data,
+            owner: PROGRAM_ID,
+            executable: false,
+            rent_epoch: 0,
+        };
+
+        let mut active_members = [[0u8; 32]; 10];
+        active_members[0] = member_1.to_bytes();
+
+        let proposal = ProposalState {
+            version: ProposalState::CURRENT_VERSION,
+            proposal_id: 0,
+            expiry: 2_000_000_000,
+            result: ProposalStatus::Active,
+            bump: 255,
+            active_members,
+            votes: [0u8; 10],
+            created_time: 1_900_000_000,
+            vote_changes: [VoteChange::EMPTY; MAX_VOTE_CHANGES],
+            change_head: 0,
+            change_len: 0,
+            action: ProposalAction::EMPTY,
+            yes_weight: 0,
+            no_weight: 0,
+            governance_action: GovernanceAction::NONE,
+            multisig: multisig_pda.to_bytes(),
+            proposer: member_1.to_bytes(),
+        };
+
+        let mut proposal_data = vec![0u8; ProposalState::LEN];
+        unsafe {
+            std::ptr::copy_nonoverlapping(
+                &proposal as *const ProposalState as *const u8,
+                proposal_data.as_mut_ptr(),
+                ProposalState::LEN,
+            );
+        }
+
+        let proposal_account = Account {
+            lamports: 1_000_000,
+            data: proposal_data,
+            owner: PROGRAM_ID,
+            executable: false,
+            rent_epoch: 0,
+        };
+
+        let proposer_account = Account {
+            lamports: 1_000_000,
+            data: vec![],
+            owner: solana_sdk::system_program::id(),
+            executable: false,
+            rent_epoch: 0,
+        };
+
+        let target_program = SdkPubkey::new_from_array([88u8; 32]);
+        let target_account = SdkPubkey::new_from_array([55u8; 32]);
+        let cpi_data = vec![9u8, 9, 9];
+
+        let mut instruction_data = vec![8u8]; // set_proposal_action discriminator
+        instruction_data.extend_from_slice(&target_program.to_bytes());
+        instruction_data.push(1); // accounts_len
+        instruction_data.extend_from_slice(&target_account.to_bytes());
+        instruction_data.push(0b10); // writable, not a signer
+        instruction_data.extend_from_slice(&(cpi_data.len() as u16).to_le_bytes());
+        instruction_data.extend_from_slice(&cpi_data);
+
+        let instruction = Instruction {
+            program_id: PROGRAM_ID,
+            accounts: vec![
+                AccountMeta::new(multisig_pda, false),
+                AccountMeta::new(proposal_pda, false),
+                AccountMeta::new_readonly(member_1, true),
+            ],
+            data: instruction_data,
+        };
+
+        mollusk.process_and_validate_instruction(
+            &instruction,
+            &vec![
+                (multisig_pda, multisig_account),
+                (proposal_pda, proposal_account),
+                (member_1, proposer_account),
+            ],
+            &[Check::success()],
+        );
+    }
+}
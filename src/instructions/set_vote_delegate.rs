@@ -0,0 +1,175 @@
+use pinocchio::{
+    account_info::AccountInfo, pubkey::Pubkey, ProgramResult, program_error::ProgramError
+};
+
+use pinocchio_log::log;
+
+use crate::state::{Multisig, NO_DELEGATE, VoteDelegate};
+
+pub fn set_vote_delegate(
+    multisig: &AccountInfo,
+    member: &AccountInfo,
+    delegate: Pubkey,
+    valid_until: u64,
+) -> ProgramResult {
+
+    if !member.is_signer() {
+        log!("Member must sign to set a vote delegate");
+        return Err(ProgramError::MissingRequiredSignature);
+    }
+
+    let multisig_account = Multisig::from_account_info(multisig)?;
+
+    let member_pubkey = *member.key();
+    let mut member_index: Option<usize> = None;
+
+    for i in 0..multisig_account.member_count as usize {
+        if multisig_account.memeber_keys[i] == member_pubkey {
+            member_index = Some(i);
+            break;
+        }
+    }
+
+    let member_index = match member_index {
+        Some(idx) => idx,
+        None => {
+            log!("Signer is not a member of the multisig");
+            return Err(ProgramError::InvalidAccountData);
+        }
+    };
+
+    let mut free_slot: Option<usize> = None;
+
+    for i in 0..multisig_account.delegates.len() {
+        let entry = &multisig_account.delegates[i];
+
+        if entry.member_index as usize == member_index {
+            free_slot = Some(i);
+            break;
+        }
+
+        if free_slot.is_none() && entry.member_index == NO_DELEGATE {
+            free_slot = Some(i);
+        }
+    }
+
+    let slot = match free_slot {
+        Some(idx) => idx,
+        None => {
+            log!("No free delegation slot available");
+            return Err(ProgramError::InvalidAccountData);
+        }
+    };
+
+    multisig_account.delegates[slot] = VoteDelegate {
+        member_index: member_index as u8,
+        delegate,
+        valid_until,
+    };
+
+    log!("Vote delegate set for member {}", member_index as u64);
+
+    Ok(())
+}
+
+// -------------------------- TESTING set_vote_delegate -----------------------------
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::instructions::layout::{MultisigInstructions, SetVoteDelegate};
+    use crate::state::{CreditBucket, MAX_CREDIT_HISTORY};
+    use mollusk_svm::{Mollusk, result::Check};
+    use solana_sdk::{
+        account::Account,
+        instruction::{AccountMeta, Instruction},
+        pubkey::Pubkey as SdkPubkey,
+        pubkey,
+    };
+
+    const PROGRAM_ID: SdkPubkey = pubkey!("3X4xfxBGSWDc24HhACGxk5VdDAJzg9mxtUvvHvwjQcec");
+    const CREATOR: SdkPubkey = SdkPubkey::new_from_array([1u8; 32]);
+
+    #[test]
+    fn test_set_vote_delegate() {
+        let mollusk = Mollusk::new(&PROGRAM_ID, "target/deploy/multisig_pinocchio");
+
+        let (multisig_pda, _) = SdkPubkey::find_program_address(
+            &[b"multisig", CREATOR.as_ref()],
+            &PROGRAM_ID
+        );
+
+        let member_1 = SdkPubkey::new_from_array([10u8; 32]);
+        let delegate = SdkPubkey::new_from_array([77u8; 32]);
+
+        let mut member_keys = [[0u8; 32]; 10];
+        member_keys[0] = member_1.to_bytes();
+
+        let multisig = Multisig {
+            version: Multisig::CURRENT_VERSION,
+            creator: CREATOR.to_bytes(),
+            member_count: 1,
+            memeber_keys: member_keys,
+            threshold: 1,
+            proposal_expiry: 86400,
+            total_proposals: 0,
+            treasury_wallet: SdkPubkey::new_from_array([99u8; 32]).to_bytes(),
+            config_bump: 255,
+            treasury_bump: 254,
+            delegates: [VoteDelegate::EMPTY; 10],
+            credit_history: [CreditBucket::EMPTY; MAX_CREDIT_HISTORY],
+            credit_head: 0,
+            credit_len: 0,
+            member_weights: [1u64; 10],
+        };
+
+        let mut account_data = vec![0u8; Multisig::LEN];
+        unsafe {
+            std::ptr::copy_nonoverlapping(
+                &multisig as *const Multisig as *const u8,
+                account_data.as_mut_ptr(),
+                Multisig::LEN,
+            );
+        }
+
+        let multisig_account = Account {
+            lamports: 1_000_000,
+            data: account_data,
+            owner: PROGRAM_ID,
+            executable: false,
+            rent_epoch: 0,
+        };
+
+        let member_account = Account {
+            lamports: 1_000_000,
+            data: vec![],
+            owner: solana_sdk::system_program::id(),
+            executable: false,
+            rent_epoch: 0,
+        };
+
+        let mut instruction_data = vec![MultisigInstructions::SetVoteDelegate as u8];
+        instruction_data.extend_from_slice(&SetVoteDelegate {
+            delegate: delegate.to_bytes(),
+            valid_until: i64::MAX as u64,
+        }.encode());
+
+        let instruction = Instruction {
+            program_id: PROGRAM_ID,
+            accounts: vec![
+                AccountMeta::new(multisig_pda, false),
+                AccountMeta::new_readonly(member_1, true),
+            ],
+            data: instruction_data,
+        };
+
+        mollusk.process_and_validate_instruction(
+            &instruction,
+            &vec![
+                (multisig_pda, multisig_account),
+                (member_1, member_account),
+            ],
+            &[Check::success()],
+        );
+    }
+}
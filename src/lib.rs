@@ -5,6 +5,7 @@ use pinocchio::{
     pubkey::{self, Pubkey},
     ProgramResult,
 };
+use pinocchio_log::log;
 
 entrypoint!(process_instruction);
 
@@ -24,54 +25,80 @@ pub fn process_instruction(
     assert_eq!(program_id, &ID);
 
     let (discriminator, data) = instruction_data.split_first().ok_or(ProgramError::InvalidInstructionData)?;
+    let instruction = MultisigInstructions::try_from(discriminator)?;
 
-    match *discriminator {
-        0 => {
-            initialize_multisig(accounts, data)
+    match instruction {
+        MultisigInstructions::InitializeMultisig => {
+            let payload = InitMultisig::decode(data)?;
+            initialize_multisig(accounts, &payload)
         }
-        1 => {
-            let member = read_pubkey(data)?;
-            let multisig = &accounts[0];
-            add_member(multisig, member)
+        MultisigInstructions::AddMember => {
+            // Membership changes are now gated behind a passed proposal and applied
+            // by `execute_proposal`; this entry point is no longer directly callable.
+            log!("add_member is only callable via a passed proposal's execute step");
+            Err(ProgramError::InvalidInstructionData)
         }
-        2 => {
-            let member = read_pubkey(data)?;
-            let multisig = &accounts[0];
-            remove_member(multisig, member)
+        MultisigInstructions::RemoveMember => {
+            log!("remove_member is only callable via a passed proposal's execute step");
+            Err(ProgramError::InvalidInstructionData)
         }
-        3 => {
-            let proposal_expiry_duration = read_u64(&data[96..])?;
+        MultisigInstructions::InitializeProposal => {
+            let payload = InitProposal::decode(data)?;
             let multisig = &accounts[0];
-            let proposal = &accounts[1]; 
+            let proposal = &accounts[1];
             let proposer = &accounts[2];
-            init_proposal(multisig, proposal, proposer, proposal_expiry_duration)
+            init_proposal(multisig, proposal, proposer, payload.expiry_duration)
         }
-        4 => {
-            let vote_type = data[128];
+        MultisigInstructions::Vote => {
+            let payload = Vote::decode(data)?;
             let multisig = &accounts[0];
             let proposal = &accounts[1];
             let voter = &accounts[2];
             let vote_account = &accounts[3];
-            process_vote(multisig, proposal, voter, vote_account, vote_type)
+            process_vote(multisig, proposal, voter, vote_account, payload.vote_type)
+        }
+        MultisigInstructions::SetVoteDelegate => {
+            let payload = SetVoteDelegate::decode(data)?;
+            let multisig = &accounts[0];
+            let member = &accounts[1];
+            set_vote_delegate(multisig, member, payload.delegate, payload.valid_until)
+        }
+        MultisigInstructions::RevokeVoteDelegate => {
+            let multisig = &accounts[0];
+            let member = &accounts[1];
+            revoke_vote_delegate(multisig, member)
+        }
+        MultisigInstructions::SetMemberWeight => {
+            // Weight changes are now gated behind a passed proposal and applied
+            // by `execute_proposal`; this entry point is no longer directly callable.
+            log!("set_member_weight is only callable via a passed proposal's execute step");
+            Err(ProgramError::InvalidInstructionData)
+        }
+        MultisigInstructions::SetProposalAction => {
+            let multisig = &accounts[0];
+            let proposal = &accounts[1];
+            let proposer = &accounts[2];
+            set_proposal_action(multisig, proposal, proposer, data)
+        }
+        MultisigInstructions::ExecuteProposal => {
+            let multisig = &accounts[0];
+            let proposal = &accounts[1];
+            let treasury = &accounts[2];
+            let target_program = &accounts[3];
+            let remaining_accounts = &accounts[4..];
+            execute_proposal(multisig, proposal, treasury, target_program, remaining_accounts)
+        }
+        MultisigInstructions::SetGovernanceAction => {
+            let payload = SetGovernanceAction::decode(data)?;
+            let multisig = &accounts[0];
+            let proposal = &accounts[1];
+            let proposer = &accounts[2];
+            set_governance_action(multisig, proposal, proposer, payload.kind, payload.member, payload.weight)
+        }
+        MultisigInstructions::CloseProposal => {
+            let proposal = &accounts[0];
+            let proposer = &accounts[1];
+            close_proposal(proposal, proposer)
         }
-        _ => Err(ProgramError::InvalidInstructionData),
-    }
-}
-
-fn read_pubkey(data: &[u8]) -> Result<Pubkey, ProgramError> {
-    if data.len() < 32 {
-        return Err(ProgramError::InvalidInstructionData);
-    }
-    let mut key_bytes = [0u8; 32];
-    key_bytes.copy_from_slice(&data[..32]);
-    Ok(Pubkey::from(key_bytes))
-}
-
-fn read_u64(data: &[u8]) -> Result<u64, ProgramError> {
-    if data.len() < 8 {
-        return Err(ProgramError::InvalidInstructionData);
     }
-    let mut bytes = [0u8; 8];
-    bytes.copy_from_slice(&data[..8]);
-    Ok(u64::from_le_bytes(bytes))
 }
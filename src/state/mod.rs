@@ -0,0 +1,11 @@
+pub mod multisig;
+pub use multisig::*;
+
+pub mod proposal;
+pub use proposal::*;
+
+pub mod vote;
+pub use vote::*;
+
+pub mod packed_state;
+pub use packed_state::*;
@@ -0,0 +1,229 @@
+use pinocchio::{
+    account_info::AccountInfo,
+    program_error::ProgramError,
+    pubkey::Pubkey,
+};
+
+/// Sentinel `member_index` marking an unused delegate slot.
+pub const NO_DELEGATE: u8 = u8::MAX;
+
+/// A single `set_vote_delegate` grant: `delegate` may sign votes on behalf of
+/// `memeber_keys[member_index]` until `valid_until` (unix timestamp), mirroring
+/// the Solana vote program's per-epoch `AuthorizedVoters` map.
+#[repr(C)]
+#[derive(Clone, Copy)]
+pub struct VoteDelegate {
+    pub member_index: u8,
+    pub delegate: Pubkey,
+    pub valid_until: u64,
+}
+
+impl VoteDelegate {
+    pub const EMPTY: Self = Self {
+        member_index: NO_DELEGATE,
+        delegate: [0u8; 32],
+        valid_until: 0,
+    };
+}
+
+/// Number of recent voting-credit buckets retained per multisig, mirroring the
+/// vote program's bounded `epoch_credits` history (there capped at 64 epochs).
+pub const MAX_CREDIT_HISTORY: usize = 8;
+
+/// Per-member participation credits accrued for one coarse time bucket
+/// (`unix_timestamp / proposal_expiry`).
+#[repr(C)]
+#[derive(Clone, Copy)]
+pub struct CreditBucket {
+    pub bucket: u64,
+    pub credits: [u64; 10],
+}
+
+impl CreditBucket {
+    pub const EMPTY: Self = Self {
+        bucket: 0,
+        credits: [0u64; 10],
+    };
+}
+
+#[repr(C)]
+pub struct Multisig {
+    pub version: u8,
+    pub creator: Pubkey,
+    pub member_count: u64,
+    pub memeber_keys: [Pubkey; 10],
+    pub threshold: u64,
+    pub proposal_expiry: u64,
+    pub total_proposals: u64,
+    pub treasury_wallet: Pubkey,
+    pub config_bump: u8,
+    pub treasury_bump: u8,
+    pub delegates: [VoteDelegate; 10],
+    pub credit_history: [CreditBucket; MAX_CREDIT_HISTORY],
+    pub credit_head: u8,
+    pub credit_len: u8,
+    pub member_weights: [u64; 10],
+}
+
+/// Pre-versioning layout (every field of `MultisigV1` minus the leading `version`
+/// discriminant). Kept only so `from_account_info` can migrate accounts created
+/// before versioning was introduced.
+#[repr(C)]
+struct MultisigV0 {
+    creator: Pubkey,
+    member_count: u64,
+    memeber_keys: [Pubkey; 10],
+    threshold: u64,
+    proposal_expiry: u64,
+    total_proposals: u64,
+    treasury_wallet: Pubkey,
+    config_bump: u8,
+    treasury_bump: u8,
+    delegates: [VoteDelegate; 10],
+    credit_history: [CreditBucket; MAX_CREDIT_HISTORY],
+    credit_head: u8,
+    credit_len: u8,
+}
+
+impl MultisigV0 {
+    const LEN: usize = core::mem::size_of::<Self>();
+}
+
+/// Version-1 layout (adds the `version` discriminant but predates per-member
+/// voting weights). Kept so `from_account_info` can migrate to version 2.
+#[repr(C)]
+struct MultisigV1 {
+    version: u8,
+    creator: Pubkey,
+    member_count: u64,
+    memeber_keys: [Pubkey; 10],
+    threshold: u64,
+    proposal_expiry: u64,
+    total_proposals: u64,
+    treasury_wallet: Pubkey,
+    config_bump: u8,
+    treasury_bump: u8,
+    delegates: [VoteDelegate; 10],
+    credit_history: [CreditBucket; MAX_CREDIT_HISTORY],
+    credit_head: u8,
+    credit_len: u8,
+}
+
+impl MultisigV1 {
+    const LEN: usize = core::mem::size_of::<Self>();
+}
+
+impl crate::state::packed_state::PackedState for Multisig {
+    const LEN: usize = Self::LEN;
+
+    fn load(account_info: &AccountInfo) -> Result<&mut Self, ProgramError> {
+        Self::from_account_info(account_info)
+    }
+}
+
+impl Multisig {
+    pub const LEN: usize = core::mem::size_of::<Self>();
+    pub const CURRENT_VERSION: u8 = 2;
+
+    pub fn from_account_info(account_info: &AccountInfo) -> Result<&mut Self, ProgramError> {
+        if account_info.owner() != &crate::ID {
+            return Err(ProgramError::InvalidAccountOwner);
+        }
+
+        if account_info.data_len() == Self::LEN {
+            let account = unsafe {
+                &mut *(account_info.borrow_mut_data_unchecked().as_mut_ptr() as *mut Self)
+            };
+
+            // `version == 0` also covers a freshly zero-allocated account that the
+            // caller is about to initialize for the first time.
+            if account.version != 0 && account.version != Self::CURRENT_VERSION {
+                return Err(ProgramError::InvalidAccountData);
+            }
+
+            return Ok(account);
+        }
+
+        // Migrate a version-1 account that predates per-member weights: default
+        // every member to weight 1 so existing multisigs keep their prior
+        // one-member-one-vote behavior (`threshold` already read as a member count).
+        if account_info.data_len() == MultisigV1::LEN {
+            if !account_info.is_writable() {
+                return Err(ProgramError::InvalidAccountData);
+            }
+
+            let old = unsafe {
+                &*(account_info.borrow_data_unchecked().as_ptr() as *const MultisigV1)
+            };
+
+            let migrated = Multisig {
+                version: Self::CURRENT_VERSION,
+                creator: old.creator,
+                member_count: old.member_count,
+                memeber_keys: old.memeber_keys,
+                threshold: old.threshold,
+                proposal_expiry: old.proposal_expiry,
+                total_proposals: old.total_proposals,
+                treasury_wallet: old.treasury_wallet,
+                config_bump: old.config_bump,
+                treasury_bump: old.treasury_bump,
+                delegates: old.delegates,
+                credit_history: old.credit_history,
+                credit_head: old.credit_head,
+                credit_len: old.credit_len,
+                member_weights: [1u64; 10],
+            };
+
+            account_info.realloc(Self::LEN, false)?;
+
+            let account = unsafe {
+                &mut *(account_info.borrow_mut_data_unchecked().as_mut_ptr() as *mut Self)
+            };
+            *account = migrated;
+
+            return Ok(account);
+        }
+
+        // Migrate an account written before `version` existed: reconstruct the
+        // current layout with sane defaults for the new field, then rewrite the
+        // account data in place so future loads take the fast path above.
+        if account_info.data_len() == MultisigV0::LEN {
+            if !account_info.is_writable() {
+                return Err(ProgramError::InvalidAccountData);
+            }
+
+            let old = unsafe {
+                &*(account_info.borrow_data_unchecked().as_ptr() as *const MultisigV0)
+            };
+
+            let migrated = Multisig {
+                version: Self::CURRENT_VERSION,
+                creator: old.creator,
+                member_count: old.member_count,
+                memeber_keys: old.memeber_keys,
+                threshold: old.threshold,
+                proposal_expiry: old.proposal_expiry,
+                total_proposals: old.total_proposals,
+                treasury_wallet: old.treasury_wallet,
+                config_bump: old.config_bump,
+                treasury_bump: old.treasury_bump,
+                delegates: old.delegates,
+                credit_history: old.credit_history,
+                credit_head: old.credit_head,
+                credit_len: old.credit_len,
+                member_weights: [1u64; 10],
+            };
+
+            account_info.realloc(Self::LEN, false)?;
+
+            let account = unsafe {
+                &mut *(account_info.borrow_mut_data_unchecked().as_mut_ptr() as *mut Self)
+            };
+            *account = migrated;
+
+            return Ok(account);
+        }
+
+        Err(ProgramError::InvalidAccountData)
+    }
+}
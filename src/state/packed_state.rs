@@ -0,0 +1,28 @@
+use pinocchio::{
+    account_info::AccountInfo,
+    program_error::ProgramError,
+    sysvars::rent::Rent,
+    ProgramResult,
+};
+
+/// Common load/persist contract for the crate's fixed-size, raw-pointer-cast
+/// account layouts (`Multisig`, `ProposalState`), so every handler goes through
+/// the same bounds-checked load and rent-exemption assertion instead of
+/// re-deriving them per instruction.
+pub trait PackedState: Sized {
+    const LEN: usize;
+
+    /// Bounds- and ownership-checked load, delegating to the type's own
+    /// `from_account_info` so existing version-migration behavior is preserved.
+    fn load(account_info: &AccountInfo) -> Result<&mut Self, ProgramError>;
+
+    /// Asserts the account is rent-exempt at `Self::LEN` before a caller treats
+    /// a freshly initialized account as durable.
+    fn save_rent_exempt(&self, account_info: &AccountInfo, rent: &Rent) -> ProgramResult {
+        if !rent.is_exempt(account_info.lamports(), Self::LEN) {
+            return Err(ProgramError::AccountNotRentExempt);
+        }
+
+        Ok(())
+    }
+}
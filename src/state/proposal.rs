@@ -0,0 +1,823 @@
+use pinocchio::{
+    account_info::AccountInfo,
+    program_error::ProgramError,
+    pubkey::Pubkey,
+};
+
+/// `Succeeded`/`Failed` (values 1/2) predate the weighted `yes_weight`/`no_weight`
+/// tally and are no longer produced by `process_vote`; `Passed`/`Rejected` are
+/// the one resolution path a vote now resolves to. The variants are kept (and
+/// their discriminants not reassigned) so old accounts carrying them still decode cleanly.
+#[repr(u8)]
+#[derive(Clone, Copy, PartialEq)]
+pub enum ProposalStatus {
+    Active = 0,
+    Succeeded = 1,
+    Failed = 2,
+    Executed = 3,
+    /// Cumulative `yes_weight` reached `threshold`.
+    Passed = 4,
+    /// `yes_weight` can no longer reach `threshold`.
+    Rejected = 5,
+    /// Past `expiry` when a vote (on an `Active` proposal) or an execution
+    /// attempt (on a `Passed` one) touched it.
+    Expired = 6,
+    /// Withdrawn by its proposer via `close_proposal` before reaching expiry.
+    Cancelled = 7,
+}
+
+/// Maximum accounts a proposal's CPI action can reference. Mirrors the other
+/// fixed-capacity-10 tables in this crate (`memeber_keys`, `delegates`, ...).
+pub const MAX_ACTION_ACCOUNTS: usize = 10;
+
+/// Maximum size of the embedded CPI instruction-data blob. The account can't be
+/// resized to fit an arbitrary payload, so this is a generous fixed cap instead.
+pub const MAX_ACTION_DATA: usize = 256;
+
+/// One `(pubkey, is_signer, is_writable)` entry of a proposal's CPI account list,
+/// mirroring `pinocchio::instruction::AccountMeta` in a `#[repr(C)]`-safe form.
+#[repr(C)]
+#[derive(Clone, Copy)]
+pub struct ActionAccountMeta {
+    pub pubkey: Pubkey,
+    pub is_signer: bool,
+    pub is_writable: bool,
+}
+
+impl ActionAccountMeta {
+    pub const EMPTY: Self = Self {
+        pubkey: [0u8; 32],
+        is_signer: false,
+        is_writable: false,
+    };
+}
+
+/// An embedded, tamper-evident CPI action a proposal executes once it passes.
+/// `has_action` (not `program_id == [0u8; 32]`) is the "configured" sentinel,
+/// since the System Program's address is itself the all-zero pubkey and is a
+/// legitimate CPI target (e.g. a SOL transfer out of the treasury).
+#[repr(C)]
+#[derive(Clone, Copy)]
+pub struct ProposalAction {
+    pub program_id: Pubkey,
+    pub accounts: [ActionAccountMeta; MAX_ACTION_ACCOUNTS],
+    pub accounts_len: u8,
+    pub data: [u8; MAX_ACTION_DATA],
+    pub data_len: u16,
+    pub has_action: bool,
+}
+
+impl ProposalAction {
+    pub const EMPTY: Self = Self {
+        program_id: [0u8; 32],
+        accounts: [ActionAccountMeta::EMPTY; MAX_ACTION_ACCOUNTS],
+        accounts_len: 0,
+        data: [0u8; MAX_ACTION_DATA],
+        data_len: 0,
+        has_action: false,
+    };
+}
+
+/// Pre-`has_action` layout of `ProposalAction`, kept only so `ProposalState`'s
+/// migration branches can reinterpret the raw bytes of proposals created
+/// before the flag was introduced.
+#[repr(C)]
+#[derive(Clone, Copy)]
+struct ProposalActionV0 {
+    program_id: Pubkey,
+    accounts: [ActionAccountMeta; MAX_ACTION_ACCOUNTS],
+    accounts_len: u8,
+    data: [u8; MAX_ACTION_DATA],
+    data_len: u16,
+}
+
+/// Migrates a pre-`has_action` action payload, inferring `has_action` from the
+/// old zero-pubkey sentinel it's replacing.
+fn migrate_action(old: ProposalActionV0) -> ProposalAction {
+    ProposalAction {
+        program_id: old.program_id,
+        accounts: old.accounts,
+        accounts_len: old.accounts_len,
+        data: old.data,
+        data_len: old.data_len,
+        has_action: old.program_id != [0u8; 32],
+    }
+}
+
+/// A membership or weight change a proposal can apply once it passes, alongside
+/// (and independent of) its treasury CPI `action`. Gates `add_member`/`remove_member`/
+/// `set_member_weight` behind a passed proposal instead of letting an arbitrary
+/// signer call them.
+#[repr(u8)]
+#[derive(Clone, Copy, PartialEq)]
+pub enum GovernanceActionKind {
+    None = 0,
+    AddMember = 1,
+    RemoveMember = 2,
+    SetMemberWeight = 3,
+}
+
+#[repr(C)]
+#[derive(Clone, Copy)]
+pub struct GovernanceAction {
+    pub kind: GovernanceActionKind,
+    pub member: Pubkey,
+    /// Only meaningful for `SetMemberWeight`; the weight `member` is set to.
+    pub weight: u64,
+}
+
+impl GovernanceAction {
+    pub const NONE: Self = Self {
+        kind: GovernanceActionKind::None,
+        member: [0u8; 32],
+        weight: 0,
+    };
+}
+
+/// Pre-`weight` layout of `GovernanceAction`, kept only so `ProposalState`'s
+/// migration branches can reinterpret the raw bytes of proposals created
+/// before `SetMemberWeight` was added.
+#[repr(C)]
+#[derive(Clone, Copy)]
+struct GovernanceActionV0 {
+    kind: GovernanceActionKind,
+    member: Pubkey,
+}
+
+fn migrate_governance_action(old: GovernanceActionV0) -> GovernanceAction {
+    GovernanceAction {
+        kind: old.kind,
+        member: old.member,
+        weight: 0,
+    }
+}
+
+/// Number of vote mutations retained per proposal, mirroring the vote program's
+/// bounded `MAX_LOCKOUT_HISTORY` (31) lockout-history `VecDeque`.
+pub const MAX_VOTE_CHANGES: usize = 31;
+
+/// One vote mutation: member `voter_index` changed their vote from `old_vote`
+/// to `new_vote` at `timestamp`. `old_vote == VOTE_NOT_VOTED` for a first vote.
+#[repr(C)]
+#[derive(Clone, Copy)]
+pub struct VoteChange {
+    pub voter_index: u8,
+    pub old_vote: u8,
+    pub new_vote: u8,
+    pub timestamp: u64,
+}
+
+impl VoteChange {
+    pub const EMPTY: Self = Self {
+        voter_index: 0,
+        old_vote: 0,
+        new_vote: 0,
+        timestamp: 0,
+    };
+}
+
+#[repr(C)]
+pub struct ProposalState {
+    pub version: u8,
+    pub proposal_id: u64,
+    pub expiry: u64,
+    pub result: ProposalStatus,
+    pub bump: u8,
+    pub active_members: [Pubkey; 10],
+    pub votes: [u8; 10],
+    pub created_time: u64,
+    pub vote_changes: [VoteChange; MAX_VOTE_CHANGES],
+    pub change_head: u8,
+    pub change_len: u8,
+    pub action: ProposalAction,
+    pub yes_weight: u64,
+    pub no_weight: u64,
+    pub governance_action: GovernanceAction,
+    /// The multisig this proposal was created under. Handlers that take both a
+    /// `multisig` and a `proposal` account must check this against
+    /// `*multisig.key()` before trusting the proposal's tally or votes, so an
+    /// attacker can't pair their own throwaway proposal against a victim's real
+    /// multisig/treasury.
+    pub multisig: Pubkey,
+    /// The member who created this proposal via `init_proposal`. `close_proposal`
+    /// checks the closing signer against this, not against `active_members`, so
+    /// only the original proposer (not any member the proposal happens to list)
+    /// can cancel it and reclaim its rent.
+    pub proposer: Pubkey,
+}
+
+/// Pre-versioning layout, kept only so `from_account_info` can migrate accounts
+/// created before the `version` discriminant was introduced.
+#[repr(C)]
+struct ProposalStateV0 {
+    proposal_id: u64,
+    expiry: u64,
+    result: ProposalStatus,
+    bump: u8,
+    active_members: [Pubkey; 10],
+    votes: [u8; 10],
+    created_time: u64,
+}
+
+impl ProposalStateV0 {
+    const LEN: usize = core::mem::size_of::<Self>();
+}
+
+/// Version-1 layout (adds the `version` discriminant but predates the bounded
+/// vote-change audit trail). Kept so `from_account_info` can migrate to version 2.
+#[repr(C)]
+struct ProposalStateV1 {
+    version: u8,
+    proposal_id: u64,
+    expiry: u64,
+    result: ProposalStatus,
+    bump: u8,
+    active_members: [Pubkey; 10],
+    votes: [u8; 10],
+    created_time: u64,
+}
+
+impl ProposalStateV1 {
+    const LEN: usize = core::mem::size_of::<Self>();
+}
+
+/// Version-2 layout (adds the bounded vote-change audit trail but predates the
+/// embedded CPI action payload). Kept so `from_account_info` can migrate to version 3.
+#[repr(C)]
+struct ProposalStateV2 {
+    version: u8,
+    proposal_id: u64,
+    expiry: u64,
+    result: ProposalStatus,
+    bump: u8,
+    active_members: [Pubkey; 10],
+    votes: [u8; 10],
+    created_time: u64,
+    vote_changes: [VoteChange; MAX_VOTE_CHANGES],
+    change_head: u8,
+    change_len: u8,
+}
+
+impl ProposalStateV2 {
+    const LEN: usize = core::mem::size_of::<Self>();
+}
+
+/// Version-3 layout (adds the embedded CPI action but predates the cumulative
+/// `yes_weight`/`no_weight` tally). Kept so `from_account_info` can migrate to version 4.
+#[repr(C)]
+struct ProposalStateV3 {
+    version: u8,
+    proposal_id: u64,
+    expiry: u64,
+    result: ProposalStatus,
+    bump: u8,
+    active_members: [Pubkey; 10],
+    votes: [u8; 10],
+    created_time: u64,
+    vote_changes: [VoteChange; MAX_VOTE_CHANGES],
+    change_head: u8,
+    change_len: u8,
+    action: ProposalActionV0,
+}
+
+impl ProposalStateV3 {
+    const LEN: usize = core::mem::size_of::<Self>();
+}
+
+/// Version-4 layout (adds the cumulative weighted tally but predates the
+/// gated membership-change governance action). Kept so `from_account_info`
+/// can migrate to version 5.
+#[repr(C)]
+struct ProposalStateV4 {
+    version: u8,
+    proposal_id: u64,
+    expiry: u64,
+    result: ProposalStatus,
+    bump: u8,
+    active_members: [Pubkey; 10],
+    votes: [u8; 10],
+    created_time: u64,
+    vote_changes: [VoteChange; MAX_VOTE_CHANGES],
+    change_head: u8,
+    change_len: u8,
+    action: ProposalActionV0,
+    yes_weight: u64,
+    no_weight: u64,
+}
+
+impl ProposalStateV4 {
+    const LEN: usize = core::mem::size_of::<Self>();
+}
+
+/// Version-5 layout (adds the gated membership-change governance action but
+/// predates `ProposalAction::has_action`, which replaced the ambiguous
+/// zero-pubkey "no action configured" sentinel). Kept so `from_account_info`
+/// can migrate to version 6.
+#[repr(C)]
+struct ProposalStateV5 {
+    version: u8,
+    proposal_id: u64,
+    expiry: u64,
+    result: ProposalStatus,
+    bump: u8,
+    active_members: [Pubkey; 10],
+    votes: [u8; 10],
+    created_time: u64,
+    vote_changes: [VoteChange; MAX_VOTE_CHANGES],
+    change_head: u8,
+    change_len: u8,
+    action: ProposalActionV0,
+    yes_weight: u64,
+    no_weight: u64,
+    governance_action: GovernanceActionV0,
+}
+
+impl ProposalStateV5 {
+    const LEN: usize = core::mem::size_of::<Self>();
+}
+
+/// Version-6 layout (adds `ProposalAction::has_action` but predates gating
+/// `set_member_weight` behind a governance action, i.e. `GovernanceAction::weight`).
+/// Kept so `from_account_info` can migrate to version 7.
+#[repr(C)]
+struct ProposalStateV6 {
+    version: u8,
+    proposal_id: u64,
+    expiry: u64,
+    result: ProposalStatus,
+    bump: u8,
+    active_members: [Pubkey; 10],
+    votes: [u8; 10],
+    created_time: u64,
+    vote_changes: [VoteChange; MAX_VOTE_CHANGES],
+    change_head: u8,
+    change_len: u8,
+    action: ProposalAction,
+    yes_weight: u64,
+    no_weight: u64,
+    governance_action: GovernanceActionV0,
+}
+
+impl ProposalStateV6 {
+    const LEN: usize = core::mem::size_of::<Self>();
+}
+
+/// Version-7 layout (adds `GovernanceAction::weight` but predates binding a
+/// proposal to the specific multisig it was created under). Kept so
+/// `from_account_info` can migrate to version 8.
+#[repr(C)]
+struct ProposalStateV7 {
+    version: u8,
+    proposal_id: u64,
+    expiry: u64,
+    result: ProposalStatus,
+    bump: u8,
+    active_members: [Pubkey; 10],
+    votes: [u8; 10],
+    created_time: u64,
+    vote_changes: [VoteChange; MAX_VOTE_CHANGES],
+    change_head: u8,
+    change_len: u8,
+    action: ProposalAction,
+    yes_weight: u64,
+    no_weight: u64,
+    governance_action: GovernanceAction,
+}
+
+impl ProposalStateV7 {
+    const LEN: usize = core::mem::size_of::<Self>();
+}
+
+/// Version-8 layout (adds the multisig binding but predates recording the
+/// proposal's original proposer). Kept so `from_account_info` can migrate to
+/// version 9.
+#[repr(C)]
+struct ProposalStateV8 {
+    version: u8,
+    proposal_id: u64,
+    expiry: u64,
+    result: ProposalStatus,
+    bump: u8,
+    active_members: [Pubkey; 10],
+    votes: [u8; 10],
+    created_time: u64,
+    vote_changes: [VoteChange; MAX_VOTE_CHANGES],
+    change_head: u8,
+    change_len: u8,
+    action: ProposalAction,
+    yes_weight: u64,
+    no_weight: u64,
+    governance_action: GovernanceAction,
+    multisig: Pubkey,
+}
+
+impl ProposalStateV8 {
+    const LEN: usize = core::mem::size_of::<Self>();
+}
+
+impl crate::state::packed_state::PackedState for ProposalState {
+    const LEN: usize = Self::LEN;
+
+    fn load(account_info: &AccountInfo) -> Result<&mut Self, ProgramError> {
+        Self::from_account_info(account_info)
+    }
+}
+
+impl ProposalState {
+    pub const LEN: usize = core::mem::size_of::<Self>();
+    pub const CURRENT_VERSION: u8 = 9;
+
+    pub fn from_account_info(account_info: &AccountInfo) -> Result<&mut Self, ProgramError> {
+        if account_info.owner() != &crate::ID {
+            return Err(ProgramError::InvalidAccountOwner);
+        }
+
+        if account_info.data_len() == Self::LEN {
+            let account = unsafe {
+                &mut *(account_info.borrow_mut_data_unchecked().as_mut_ptr() as *mut Self)
+            };
+
+            // `version == 0` also covers a freshly zero-allocated account that the
+            // caller is about to initialize for the first time.
+            if account.version != 0 && account.version != Self::CURRENT_VERSION {
+                return Err(ProgramError::InvalidAccountData);
+            }
+
+            return Ok(account);
+        }
+
+        if account_info.data_len() == ProposalStateV8::LEN {
+            if !account_info.is_writable() {
+                return Err(ProgramError::InvalidAccountData);
+            }
+
+            let old = unsafe {
+                &*(account_info.borrow_data_unchecked().as_ptr() as *const ProposalStateV8)
+            };
+
+            // A V8 proposal predates recording the proposer, and its true
+            // proposer can't be recovered from the account alone, so it's left
+            // as the zero pubkey: this account will never match a real signer,
+            // which is the safe failure mode for a check that exists specifically
+            // to stop anyone but the original proposer from closing it.
+            let migrated = ProposalState {
+                version: Self::CURRENT_VERSION,
+                proposal_id: old.proposal_id,
+                expiry: old.expiry,
+                result: old.result,
+                bump: old.bump,
+                active_members: old.active_members,
+                votes: old.votes,
+                created_time: old.created_time,
+                vote_changes: old.vote_changes,
+                change_head: old.change_head,
+                change_len: old.change_len,
+                action: old.action,
+                yes_weight: old.yes_weight,
+                no_weight: old.no_weight,
+                governance_action: old.governance_action,
+                multisig: old.multisig,
+                proposer: [0u8; 32],
+            };
+
+            account_info.realloc(Self::LEN, false)?;
+
+            let account = unsafe {
+                &mut *(account_info.borrow_mut_data_unchecked().as_mut_ptr() as *mut Self)
+            };
+            *account = migrated;
+
+            return Ok(account);
+        }
+
+        if account_info.data_len() == ProposalStateV7::LEN {
+            if !account_info.is_writable() {
+                return Err(ProgramError::InvalidAccountData);
+            }
+
+            let old = unsafe {
+                &*(account_info.borrow_data_unchecked().as_ptr() as *const ProposalStateV7)
+            };
+
+            // A V7 proposal predates the multisig binding, and its true multisig
+            // can't be recovered from the account alone, so it's left as the
+            // zero pubkey: this account will never match a real multisig's key,
+            // which is the safe failure mode for a check that exists specifically
+            // to stop a proposal from being paired with the wrong multisig.
+            let migrated = ProposalState {
+                version: Self::CURRENT_VERSION,
+                proposal_id: old.proposal_id,
+                expiry: old.expiry,
+                result: old.result,
+                bump: old.bump,
+                active_members: old.active_members,
+                votes: old.votes,
+                created_time: old.created_time,
+                vote_changes: old.vote_changes,
+                change_head: old.change_head,
+                change_len: old.change_len,
+                action: old.action,
+                yes_weight: old.yes_weight,
+                no_weight: old.no_weight,
+                governance_action: old.governance_action,
+                multisig: [0u8; 32],
+                proposer: [0u8; 32],
+            };
+
+            account_info.realloc(Self::LEN, false)?;
+
+            let account = unsafe {
+                &mut *(account_info.borrow_mut_data_unchecked().as_mut_ptr() as *mut Self)
+            };
+            *account = migrated;
+
+            return Ok(account);
+        }
+
+        // V5 and V6 have the same size (the `has_action` byte fills padding), so
+        // tell them apart by the stored version; a V5 account falls through below.
+        if account_info.data_len() == ProposalStateV6::LEN
+            && unsafe { account_info.borrow_data_unchecked() }[0] == 6
+        {
+            if !account_info.is_writable() {
+                return Err(ProgramError::InvalidAccountData);
+            }
+
+            let old = unsafe {
+                &*(account_info.borrow_data_unchecked().as_ptr() as *const ProposalStateV6)
+            };
+
+            // `weight` defaults to 0: a V6 proposal predates `SetMemberWeight`, so
+            // its governance action (if any) was necessarily `AddMember`/`RemoveMember`.
+            let migrated = ProposalState {
+                version: Self::CURRENT_VERSION,
+                proposal_id: old.proposal_id,
+                expiry: old.expiry,
+                result: old.result,
+                bump: old.bump,
+                active_members: old.active_members,
+                votes: old.votes,
+                created_time: old.created_time,
+                vote_changes: old.vote_changes,
+                change_head: old.change_head,
+                change_len: old.change_len,
+                action: old.action,
+                yes_weight: old.yes_weight,
+                no_weight: old.no_weight,
+                governance_action: migrate_governance_action(old.governance_action),
+                multisig: [0u8; 32],
+                proposer: [0u8; 32],
+            };
+
+            account_info.realloc(Self::LEN, false)?;
+
+            let account = unsafe {
+                &mut *(account_info.borrow_mut_data_unchecked().as_mut_ptr() as *mut Self)
+            };
+            *account = migrated;
+
+            return Ok(account);
+        }
+
+        if account_info.data_len() == ProposalStateV5::LEN {
+            if !account_info.is_writable() {
+                return Err(ProgramError::InvalidAccountData);
+            }
+
+            let old = unsafe {
+                &*(account_info.borrow_data_unchecked().as_ptr() as *const ProposalStateV5)
+            };
+
+            // `has_action` is inferred from the old zero-pubkey sentinel it replaces.
+            let migrated = ProposalState {
+                version: Self::CURRENT_VERSION,
+                proposal_id: old.proposal_id,
+                expiry: old.expiry,
+                result: old.result,
+                bump: old.bump,
+                active_members: old.active_members,
+                votes: old.votes,
+                created_time: old.created_time,
+                vote_changes: old.vote_changes,
+                change_head: old.change_head,
+                change_len: old.change_len,
+                action: migrate_action(old.action),
+                yes_weight: old.yes_weight,
+                no_weight: old.no_weight,
+                governance_action: migrate_governance_action(old.governance_action),
+                multisig: [0u8; 32],
+                proposer: [0u8; 32],
+            };
+
+            account_info.realloc(Self::LEN, false)?;
+
+            let account = unsafe {
+                &mut *(account_info.borrow_mut_data_unchecked().as_mut_ptr() as *mut Self)
+            };
+            *account = migrated;
+
+            return Ok(account);
+        }
+
+        if account_info.data_len() == ProposalStateV4::LEN {
+            if !account_info.is_writable() {
+                return Err(ProgramError::InvalidAccountData);
+            }
+
+            let old = unsafe {
+                &*(account_info.borrow_data_unchecked().as_ptr() as *const ProposalStateV4)
+            };
+
+            // A V4 proposal predates gated governance actions, so it never had one.
+            let migrated = ProposalState {
+                version: Self::CURRENT_VERSION,
+                proposal_id: old.proposal_id,
+                expiry: old.expiry,
+                result: old.result,
+                bump: old.bump,
+                active_members: old.active_members,
+                votes: old.votes,
+                created_time: old.created_time,
+                vote_changes: old.vote_changes,
+                change_head: old.change_head,
+                change_len: old.change_len,
+                action: migrate_action(old.action),
+                yes_weight: old.yes_weight,
+                no_weight: old.no_weight,
+                governance_action: GovernanceAction::NONE,
+                multisig: [0u8; 32],
+                proposer: [0u8; 32],
+            };
+
+            account_info.realloc(Self::LEN, false)?;
+
+            let account = unsafe {
+                &mut *(account_info.borrow_mut_data_unchecked().as_mut_ptr() as *mut Self)
+            };
+            *account = migrated;
+
+            return Ok(account);
+        }
+
+        if account_info.data_len() == ProposalStateV3::LEN {
+            if !account_info.is_writable() {
+                return Err(ProgramError::InvalidAccountData);
+            }
+
+            let old = unsafe {
+                &*(account_info.borrow_data_unchecked().as_ptr() as *const ProposalStateV3)
+            };
+
+            // `yes_weight`/`no_weight` default to 0: a V3 proposal predates weighted
+            // tallying, so it was necessarily already resolved under the old
+            // one-member-one-vote rules before this migration can run.
+            let migrated = ProposalState {
+                version: Self::CURRENT_VERSION,
+                proposal_id: old.proposal_id,
+                expiry: old.expiry,
+                result: old.result,
+                bump: old.bump,
+                active_members: old.active_members,
+                votes: old.votes,
+                created_time: old.created_time,
+                vote_changes: old.vote_changes,
+                change_head: old.change_head,
+                change_len: old.change_len,
+                action: migrate_action(old.action),
+                yes_weight: 0,
+                no_weight: 0,
+                governance_action: GovernanceAction::NONE,
+                multisig: [0u8; 32],
+                proposer: [0u8; 32],
+            };
+
+            account_info.realloc(Self::LEN, false)?;
+
+            let account = unsafe {
+                &mut *(account_info.borrow_mut_data_unchecked().as_mut_ptr() as *mut Self)
+            };
+            *account = migrated;
+
+            return Ok(account);
+        }
+
+        if account_info.data_len() == ProposalStateV2::LEN {
+            if !account_info.is_writable() {
+                return Err(ProgramError::InvalidAccountData);
+            }
+
+            let old = unsafe {
+                &*(account_info.borrow_data_unchecked().as_ptr() as *const ProposalStateV2)
+            };
+
+            let migrated = ProposalState {
+                version: Self::CURRENT_VERSION,
+                proposal_id: old.proposal_id,
+                expiry: old.expiry,
+                result: old.result,
+                bump: old.bump,
+                active_members: old.active_members,
+                votes: old.votes,
+                created_time: old.created_time,
+                vote_changes: old.vote_changes,
+                change_head: old.change_head,
+                change_len: old.change_len,
+                action: ProposalAction::EMPTY,
+                yes_weight: 0,
+                no_weight: 0,
+                governance_action: GovernanceAction::NONE,
+                multisig: [0u8; 32],
+                proposer: [0u8; 32],
+            };
+
+            account_info.realloc(Self::LEN, false)?;
+
+            let account = unsafe {
+                &mut *(account_info.borrow_mut_data_unchecked().as_mut_ptr() as *mut Self)
+            };
+            *account = migrated;
+
+            return Ok(account);
+        }
+
+        if account_info.data_len() == ProposalStateV1::LEN {
+            if !account_info.is_writable() {
+                return Err(ProgramError::InvalidAccountData);
+            }
+
+            let old = unsafe {
+                &*(account_info.borrow_data_unchecked().as_ptr() as *const ProposalStateV1)
+            };
+
+            let migrated = ProposalState {
+                version: Self::CURRENT_VERSION,
+                proposal_id: old.proposal_id,
+                expiry: old.expiry,
+                result: old.result,
+                bump: old.bump,
+                active_members: old.active_members,
+                votes: old.votes,
+                created_time: old.created_time,
+                vote_changes: [VoteChange::EMPTY; MAX_VOTE_CHANGES],
+                change_head: 0,
+                change_len: 0,
+                action: ProposalAction::EMPTY,
+                yes_weight: 0,
+                no_weight: 0,
+                governance_action: GovernanceAction::NONE,
+                multisig: [0u8; 32],
+                proposer: [0u8; 32],
+            };
+
+            account_info.realloc(Self::LEN, false)?;
+
+            let account = unsafe {
+                &mut *(account_info.borrow_mut_data_unchecked().as_mut_ptr() as *mut Self)
+            };
+            *account = migrated;
+
+            return Ok(account);
+        }
+
+        if account_info.data_len() == ProposalStateV0::LEN {
+            if !account_info.is_writable() {
+                return Err(ProgramError::InvalidAccountData);
+            }
+
+            let old = unsafe {
+                &*(account_info.borrow_data_unchecked().as_ptr() as *const ProposalStateV0)
+            };
+
+            let migrated = ProposalState {
+                version: Self::CURRENT_VERSION,
+                proposal_id: old.proposal_id,
+                expiry: old.expiry,
+                result: old.result,
+                bump: old.bump,
+                active_members: old.active_members,
+                votes: old.votes,
+                created_time: old.created_time,
+                vote_changes: [VoteChange::EMPTY; MAX_VOTE_CHANGES],
+                change_head: 0,
+                change_len: 0,
+                action: ProposalAction::EMPTY,
+                yes_weight: 0,
+                no_weight: 0,
+                governance_action: GovernanceAction::NONE,
+                multisig: [0u8; 32],
+                proposer: [0u8; 32],
+            };
+
+            account_info.realloc(Self::LEN, false)?;
+
+            let account = unsafe {
+                &mut *(account_info.borrow_mut_data_unchecked().as_mut_ptr() as *mut Self)
+            };
+            *account = migrated;
+
+            return Ok(account);
+        }
+
+        Err(ProgramError::InvalidAccountData)
+    }
+}
@@ -0,0 +1,81 @@
+use pinocchio::{
+    account_info::AccountInfo,
+    program_error::ProgramError,
+};
+
+#[repr(C)]
+pub struct VoteState {
+    pub version: u8,
+    pub has_permission: bool,
+    pub vote_count: u64,
+    pub bump: u8,
+    pub votes: [u8; 10],
+}
+
+/// Pre-versioning layout, kept only so `from_account_info` can migrate accounts
+/// created before the `version` discriminant was introduced.
+#[repr(C)]
+struct VoteStateV0 {
+    has_permission: bool,
+    vote_count: u64,
+    bump: u8,
+    votes: [u8; 10],
+}
+
+impl VoteStateV0 {
+    const LEN: usize = core::mem::size_of::<Self>();
+}
+
+impl VoteState {
+    pub const LEN: usize = core::mem::size_of::<Self>();
+    pub const CURRENT_VERSION: u8 = 1;
+
+    pub fn from_account_info(account_info: &AccountInfo) -> Result<&mut Self, ProgramError> {
+        if account_info.owner() != &crate::ID {
+            return Err(ProgramError::InvalidAccountOwner);
+        }
+
+        if account_info.data_len() == Self::LEN {
+            let account = unsafe {
+                &mut *(account_info.borrow_mut_data_unchecked().as_mut_ptr() as *mut Self)
+            };
+
+            // `version == 0` also covers a freshly zero-allocated account that the
+            // caller is about to initialize for the first time.
+            if account.version != 0 && account.version != Self::CURRENT_VERSION {
+                return Err(ProgramError::InvalidAccountData);
+            }
+
+            return Ok(account);
+        }
+
+        if account_info.data_len() == VoteStateV0::LEN {
+            if !account_info.is_writable() {
+                return Err(ProgramError::InvalidAccountData);
+            }
+
+            let old = unsafe {
+                &*(account_info.borrow_data_unchecked().as_ptr() as *const VoteStateV0)
+            };
+
+            let migrated = VoteState {
+                version: Self::CURRENT_VERSION,
+                has_permission: old.has_permission,
+                vote_count: old.vote_count,
+                bump: old.bump,
+                votes: old.votes,
+            };
+
+            account_info.realloc(Self::LEN, false)?;
+
+            let account = unsafe {
+                &mut *(account_info.borrow_mut_data_unchecked().as_mut_ptr() as *mut Self)
+            };
+            *account = migrated;
+
+            return Ok(account);
+        }
+
+        Err(ProgramError::InvalidAccountData)
+    }
+}